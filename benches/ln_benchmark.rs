@@ -8,6 +8,7 @@ criterion_group!(
 );
 criterion_main!(benches);
 
+#[allow(clippy::single_element_loop)]
 fn bench_ln(c: &mut Criterion) {
     let mut group = c.benchmark_group("ln fixed point integer");
 
@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion, BenchmarkId};
-use logarithm::decimal::FixedPoint;
+use logarithm::fixed_point::FixedPoint;
 use checked_decimal_macro::*;
 
 criterion_group!(
@@ -8,6 +8,7 @@ criterion_group!(
 );
 criterion_main!(benches);
 
+#[allow(clippy::single_element_loop)]
 fn bench_ln(c: &mut Criterion) {
     let mut group = c.benchmark_group("msb fixed point integer");
 
@@ -0,0 +1,182 @@
+use crate::decimal::core::parse::parse_decimal;
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::format::{to_digits, MAX_DIGITS};
+use crate::decimal::GenericDecimal;
+
+impl<T: DecimalStorage> GenericDecimal<T> {
+    /// Write the decimal representation of a [GenericDecimal] into a byte buffer without
+    /// allocating, in the same format as [Display](core::fmt::Display), e.g. `-0.500000`.
+    /// Returns the number of bytes written, or [ErrorCode::BufferTooSmall] with the number of
+    /// bytes required, in which case the buffer is left untouched.
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, ErrorCode> {
+        write_digits(self.value, self.scale, self.negative, buf)
+    }
+
+    /// Parse a [GenericDecimal] from ASCII bytes without allocating, with the same grammar and
+    /// errors as [FromStr](core::str::FromStr), for the full range of the backing integer at
+    /// scales up to its [DecimalStorage::MAX_SCALE].
+    pub fn from_ascii(bytes: &[u8]) -> Result<Self, ErrorCode> {
+        let parsed = parse_decimal(bytes, T::MAX, T::MAX_SCALE)?;
+
+        Ok(Self::new(parsed.value, parsed.scale, parsed.negative))
+    }
+}
+
+fn write_digits<T: UnsignedInt>(
+    value: T,
+    scale: u8,
+    negative: bool,
+    buf: &mut [u8],
+) -> Result<usize, ErrorCode> {
+    let mut stack = [0u8; MAX_DIGITS];
+    let digits = to_digits(value, &mut stack).as_bytes();
+    let len = digits.len();
+
+    // zero padded digits, so there is always at least one integer digit
+    let scale = scale as usize;
+    let padded = len.max(scale + 1);
+    let required = usize::from(negative) + padded + usize::from(scale > 0);
+    if buf.len() < required {
+        return Err(ErrorCode::BufferTooSmall { required });
+    }
+
+    let mut position = 0;
+    if negative {
+        buf[position] = b'-';
+        position += 1;
+    }
+    for i in (0..padded).rev() {
+        buf[position] = if i < len { digits[len - 1 - i] } else { b'0' };
+        position += 1;
+        if i == scale && scale > 0 {
+            buf[position] = b'.';
+            position += 1;
+        }
+    }
+
+    Ok(position)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_write_to() {
+        let mut buf = [0u8; 64];
+
+        {
+            let decimal = Decimal::new(500_000, 6, true);
+            let len = decimal.write_to(&mut buf).unwrap();
+            assert_eq!(&buf[..len], b"-0.500000");
+        }
+
+        {
+            let decimal = Decimal::new(42, 0, false);
+            let len = decimal.write_to(&mut buf).unwrap();
+            assert_eq!(&buf[..len], b"42");
+        }
+
+        {
+            let decimal = Decimal::new(0, 3, false);
+            let len = decimal.write_to(&mut buf).unwrap();
+            assert_eq!(&buf[..len], b"0.000");
+        }
+
+        {
+            let decimal = BigDecimal::new(U192::MAX, 30, false);
+            let len = decimal.write_to(&mut buf).unwrap();
+            assert_eq!(
+                &buf[..len],
+                b"6277101735386680763835789423.207666416102355444464034512895"
+            );
+        }
+
+        {
+            // error: "-1.5" needs 4 bytes
+            let decimal = Decimal::new(15, 1, true);
+            let mut small = [0u8; 3];
+            assert_eq!(
+                decimal.write_to(&mut small),
+                Err(ErrorCode::BufferTooSmall { required: 4 })
+            );
+            assert_eq!(small, [0u8; 3]);
+        }
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        assert_eq!(
+            Decimal::from_ascii(b"-1_000.5"),
+            Ok(Decimal::new(10005, 1, true))
+        );
+        assert_eq!(
+            BigDecimal::from_ascii(b"1e50"),
+            Ok(BigDecimal::new(
+                U192::from(10).pow(U192::from(50)),
+                0,
+                false
+            ))
+        );
+        assert_eq!(
+            Decimal::from_ascii(b"1.2\xff"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 3 })
+        );
+
+        // test: 10^-57 is the smallest big decimal
+        assert_eq!(
+            BigDecimal::from_ascii(b"1e-57"),
+            Ok(BigDecimal::new(U192::from(1), 57, false))
+        );
+
+        // error: a scale above 57, whose denominator does not fit
+        assert_eq!(
+            BigDecimal::from_ascii(b"1e-58"),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+        assert_eq!(
+            BigDecimal::from_ascii(b"0.0000000000000000000000000000000000000000000000000000000001"),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_write_to_matches_display(value: u128, scale in 0..=38u8, negative: bool) {
+            let decimal = Decimal::new(value, scale, negative);
+            let mut buf = [0u8; 128];
+            let len = decimal.write_to(&mut buf).unwrap();
+
+            let expected = decimal.to_string();
+            prop_assert_eq!(&buf[..len], expected.as_bytes());
+
+            let parsed = Decimal::from_ascii(&buf[..len]).unwrap();
+            prop_assert_eq!(parsed.value, value);
+            prop_assert_eq!(parsed.scale, scale);
+            prop_assert_eq!(parsed.negative, negative);
+        }
+
+        #[test]
+        fn test_big_decimal_write_to_round_trip(
+            limbs: [u64; 3],
+            scale in 0..=57u8,
+            negative: bool,
+        ) {
+            let decimal = BigDecimal::new(U192(limbs), scale, negative);
+            let mut buf = [0u8; 512];
+            let len = decimal.write_to(&mut buf).unwrap();
+
+            let expected = decimal.to_string();
+            prop_assert_eq!(&buf[..len], expected.as_bytes());
+
+            let parsed = BigDecimal::from_ascii(&buf[..len]).unwrap();
+            prop_assert_eq!(parsed.value, decimal.value);
+            prop_assert_eq!(parsed.scale, decimal.scale);
+            prop_assert_eq!(parsed.negative, decimal.negative);
+        }
+    }
+}
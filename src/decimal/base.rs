@@ -1,16 +1,20 @@
-use crate::decimal::core::uint::U192;
-use crate::decimal::ops::{DivUp, MulUp, Sub};
+pub use crate::decimal::core::uint::U192;
+use crate::decimal::core::{DecimalStorage, RoundingMode, UnsignedInt};
 use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::Sub;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
 use num_traits::FromPrimitive;
-use std::cmp::Ordering;
-use std::fmt;
-use std::str::FromStr;
-use thiserror::Error;
 
 /// Internal scale used for high precision compute operations
 pub const COMPUTE_SCALE: u8 = 12;
 pub const BIG_COMPUTE_SCALE: u8 = 18;
 
+/// Largest scale whose denominator still fits, 10^38 for a u128 and 10^57 for a U192
+pub const MAX_SCALE: u8 = 38;
+pub const BIG_MAX_SCALE: u8 = 57;
+
 /// Constants for compute scale
 pub const ZERO_POINT_TWO_FIVE: u128 = 250_000_000_000;
 pub const ZERO_POINT_FIVE: u128 = 500_000_000_000;
@@ -27,33 +31,333 @@ pub const BIG_ONE_POINT_TWO_FIVE: [u64; 3] = [1250000000000000000, 0, 0];
 pub const BIG_ONE_POINT_FIVE: [u64; 3] = [1500000000000000000, 0, 0];
 pub const BIG_TWO: [u64; 3] = [2000000000000000000, 0, 0];
 
-/// [Decimal] representation of a number with a value, scale (precision in terms of number of decimal places
-/// and a negative boolean to handle signed arithmetic.
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Debug, Error)]
-pub struct Decimal {
-    pub value: u128,
+/// [GenericDecimal] representation of a number with a value, scale (precision in terms of number of
+/// decimal places) and a negative boolean to handle signed arithmetic. The value is any
+/// [DecimalStorage] integer, and arithmetic is implemented once for all of them.
+#[derive(Default, Clone, Copy)]
+pub struct GenericDecimal<T: DecimalStorage> {
+    pub value: T,
     pub scale: u8,
     pub negative: bool,
 }
 
-/// [BigDecimal] representation of a number with a value, scale (precision in terms of number of decimal places
-/// and a negative boolean to handle signed arithmetic.
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Debug)]
-pub struct BigDecimal {
-    pub value: U192,
-    pub scale: u8,
-    pub negative: bool,
+/// [Decimal] representation of a number with a u128 value, scale (precision in terms of number
+/// of decimal places) and a negative boolean to handle signed arithmetic.
+pub type Decimal = GenericDecimal<u128>;
+
+/// [BigDecimal] representation of a number with a U192 value, scale (precision in terms of
+/// number of decimal places) and a negative boolean to handle signed arithmetic.
+pub type BigDecimal = GenericDecimal<U192>;
+
+/// [Decimal64] representation of a number with a u64 value, scale (precision in terms of
+/// number of decimal places) and a negative boolean to handle signed arithmetic.
+pub type Decimal64 = GenericDecimal<u64>;
+
+#[cfg(feature = "std")]
+impl std::error::Error for Decimal {}
+
+/// Debug output names the backing type, e.g. `Decimal { value: 15, scale: 1, negative: false }`.
+impl<T: DecimalStorage> fmt::Debug for GenericDecimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct(T::NAME)
+            .field("value", &self.value)
+            .field("scale", &self.scale)
+            .field("negative", &self.negative)
+            .finish()
+    }
 }
 
-impl BigDecimal {
-    pub fn new(value: U192, scale: u8, negative: bool) -> Self {
+impl<T: DecimalStorage> GenericDecimal<T> {
+    /// Create a new [GenericDecimal] from its value, scale and negative parts, a zero value is
+    /// never negative.
+    pub fn new(value: T, scale: u8, negative: bool) -> Self {
         Self {
             value,
             scale,
-            negative,
+            negative: negative && !value.is_zero(),
+        }
+    }
+
+    /// Largest representable [GenericDecimal] at the given scale.
+    pub fn max_value(scale: u8) -> Self {
+        Self::new(T::MAX, scale, false)
+    }
+
+    /// Smallest (most negative) representable [GenericDecimal] at the given scale.
+    pub fn min_value(scale: u8) -> Self {
+        Self::new(T::MAX, scale, true)
+    }
+
+    /// Show the scale of a [GenericDecimal] expressed as a power of 10.
+    pub fn denominator(self) -> T {
+        T::checked_pow10(self.scale.into()).expect("denominator")
+    }
+
+    /// Modify the scale (precision) of a [GenericDecimal] to a different scale.
+    pub fn to_scale(self, scale: u8) -> Self {
+        Self::new(
+            match self.scale.cmp(&scale) {
+                Ordering::Equal => self.value,
+                Ordering::Greater => self
+                    .value
+                    .checked_div(
+                        T::checked_pow10((self.scale - scale).into()).expect("scaled_down"),
+                    )
+                    .expect("scaled_down"),
+                _ => self
+                    .value
+                    .checked_mul(T::checked_pow10((scale - self.scale).into()).expect("scaled_up"))
+                    .expect("scaled_up"),
+            },
+            scale,
+            self.negative,
+        )
+    }
+
+    /// Modify the scale (precision) of a [GenericDecimal] to a different scale and round up the
+    /// value, i.e. away from zero, keeping its sign.
+    pub fn to_scale_up(self, scale: u8) -> Self {
+        if self.scale <= scale {
+            return self.to_scale(scale);
+        }
+
+        // a factor beyond the backing integer leaves at most the rounded up unit
+        let value = match T::checked_pow10((self.scale - scale).into()) {
+            Some(factor) if (self.value % factor).is_zero() => self.value / factor,
+            Some(factor) => self.value / factor + T::from_small(1),
+            None if self.value.is_zero() => T::zero(),
+            None => T::from_small(1),
+        };
+
+        Self::new(value, scale, self.negative)
+    }
+
+    /// Modify the scale (precision) of a [GenericDecimal] to a different scale, rounding any
+    /// discarded digits with the given [RoundingMode].
+    pub fn to_scale_with(self, scale: u8, mode: RoundingMode) -> Self {
+        match self.scale.cmp(&scale) {
+            Ordering::Greater => Self::new(
+                mode.div_pow10(self.value, (self.scale - scale).into(), self.negative),
+                scale,
+                self.negative,
+            ),
+            _ => self.to_scale(scale),
+        }
+    }
+
+    /// Modify the scale (precision) of a [GenericDecimal] to a different scale, returning
+    /// [ErrorCode::ExceedsRange] instead of panicking if the value does not fit.
+    pub fn checked_to_scale(self, scale: u8) -> Result<Self, ErrorCode> {
+        Ok(Self::new(
+            match self.scale.cmp(&scale) {
+                Ordering::Equal => self.value,
+                // a factor beyond the backing integer always truncates the value to zero
+                Ordering::Greater => match T::checked_pow10((self.scale - scale).into()) {
+                    Some(factor) => self.value / factor,
+                    None => T::zero(),
+                },
+                Ordering::Less => T::checked_pow10((scale - self.scale).into())
+                    .and_then(|factor| self.value.checked_mul(factor))
+                    .ok_or(ErrorCode::ExceedsRange)?,
+            },
+            scale,
+            self.negative,
+        ))
+    }
+
+    /// The value of a [GenericDecimal] at a scale at least as large as its own, in the
+    /// [DecimalStorage::Wide] integer, or `None` if it does not fit.
+    pub(crate) fn widen(self, scale: u8) -> Option<T::Wide> {
+        T::Wide::checked_pow10((scale - self.scale).into())
+            .and_then(|factor| T::Wide::from(self.value).checked_mul(factor))
+    }
+
+    /// Returns bit length of the [GenericDecimal] value.
+    pub fn bit_length(self) -> u32 {
+        self.value.bits()
+    }
+
+    /// Returns true if [GenericDecimal] is positive and false if the number is zero or negative.
+    pub fn is_positive(self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    /// Returns true if [GenericDecimal] is negative and false if the number is zero or positive.
+    pub fn is_negative(self) -> bool {
+        self.negative && !self.is_zero()
+    }
+
+    /// Returns true if [GenericDecimal] value is zero.
+    pub fn is_zero(self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns true if and only if the [GenericDecimal] is an exact integer.
+    pub fn is_integer(self) -> bool {
+        (self.value % self.denominator()).is_zero()
+    }
+
+    /// Returns the canonical form of a [GenericDecimal] at the same scale, clearing the sign of
+    /// a negative zero built from its public fields.
+    pub fn normalize(self) -> Self {
+        Self::new(self.value, self.scale, self.negative)
+    }
+
+    /// Returns the [GenericDecimal] at the smallest scale that preserves its value, e.g. 1.500
+    /// (scale 3) reduces to 1.5 (scale 1) and any zero reduces to 0 (scale 0).
+    pub fn reduce(self) -> Self {
+        let ten = T::from_small(10);
+        let (mut value, mut scale) = (self.value, self.scale);
+        while scale > 0 && (value % ten).is_zero() {
+            value = value / ten;
+            scale -= 1;
+        }
+
+        Self::new(value, scale, self.negative)
+    }
+
+    /// Returns the absolute value of a [GenericDecimal], at the same scale.
+    pub fn abs(self) -> Self {
+        Self::new(self.value, self.scale, false)
+    }
+
+    /// Returns the integer part of a [GenericDecimal], rounded towards zero, at the same scale.
+    pub fn trunc(self) -> Self {
+        self.round_within(self.scale, false)
+            .expect("decimal: truncation never grows the value")
+    }
+
+    /// Returns the fractional part of a [GenericDecimal], with the sign of the number, so that
+    /// `x.trunc() + x.fract() == x`.
+    pub fn fract(self) -> Self {
+        Self::new(self.value - self.trunc().value, self.scale, self.negative)
+    }
+
+    /// Returns the largest integer less than or equal to a [GenericDecimal], at the same scale:
+    /// 1.5 -> 1, -1.5 -> -2.
+    ///
+    /// Returns [ErrorCode::ExceedsRange] if the integer does not fit at the scale of the number.
+    pub fn floor(self) -> Result<Self, ErrorCode> {
+        self.round_within(self.scale, self.negative)
+    }
+
+    /// Returns the smallest integer greater than or equal to a [GenericDecimal], at the same
+    /// scale: 1.5 -> 2, -1.5 -> -1.
+    ///
+    /// Returns [ErrorCode::ExceedsRange] if the integer does not fit at the scale of the number.
+    pub fn ceil(self) -> Result<Self, ErrorCode> {
+        self.round_within(self.scale, !self.negative)
+    }
+
+    /// Rounds a [GenericDecimal] to `dp` decimal places, ties away from zero, at the same scale:
+    /// 2.5 -> 3, -2.5 -> -3.
+    ///
+    /// Returns [ErrorCode::ExceedsRange] if the rounded value does not fit at the scale of the
+    /// number.
+    pub fn round(self, dp: u8) -> Result<Self, ErrorCode> {
+        if dp >= self.scale {
+            return Ok(self);
+        }
+
+        let factor = T::checked_pow10((self.scale - dp).into()).expect("decimal: scale");
+        let remainder = self.value % factor;
+        self.round_within(self.scale - dp, remainder >= factor - remainder)
+    }
+
+    /// Clears the last `digits` digits of the value, without leaving the current scale, and adds
+    /// one unit of the last digit kept if `up` and any of them was nonzero.
+    fn round_within(self, digits: u8, up: bool) -> Result<Self, ErrorCode> {
+        let factor = T::checked_pow10(digits.into()).expect("decimal: scale");
+        let remainder = self.value % factor;
+        let value = if up && !remainder.is_zero() {
+            (self.value - remainder)
+                .checked_add(factor)
+                .ok_or(ErrorCode::ExceedsRange)?
+        } else {
+            self.value - remainder
+        };
+
+        Ok(Self::new(value, self.scale, self.negative))
+    }
+
+    /// Returns 1, 0 or -1 at the scale of the [GenericDecimal], following its sign.
+    pub fn signum(self) -> Self {
+        if self.is_zero() {
+            Self::new(T::zero(), self.scale, false)
+        } else {
+            Self::new(self.denominator(), self.scale, self.negative)
+        }
+    }
+
+    /// Restricts a [GenericDecimal] to the range [min, max], at the same scale. Bounds are
+    /// rescaled rounding into the range, so the result never lies outside of it.
+    ///
+    /// Panics if min is greater than max.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        if min > max {
+            panic!(
+                "decimal: min is greater than max in method {}::clamp()",
+                T::NAME
+            );
+        }
+
+        let lower = if min.negative {
+            min.to_scale(self.scale)
+        } else {
+            min.to_scale_up(self.scale)
+        };
+        let upper = if max.negative {
+            max.to_scale_up(self.scale)
+        } else {
+            max.to_scale(self.scale)
+        };
+
+        if self < lower {
+            lower
+        } else if self > upper {
+            upper
+        } else {
+            self
         }
     }
 
+    /// Returns the absolute difference |self - rhs| of two [GenericDecimal] with the same scale.
+    pub fn abs_diff(self, rhs: Self) -> Result<Self, ErrorCode> {
+        let difference = self.sub(rhs)?;
+        Ok(Self::new(difference.value, difference.scale, false))
+    }
+
+    /// Euclidean division of two [GenericDecimal] with the same scale, returning the integer
+    /// quotient q and the remainder r such that self = q · rhs + r and 0 <= r < |rhs|. Unlike
+    /// `%`, which keeps the sign of self, the remainder is never negative.
+    pub fn div_rem(self, rhs: Self) -> Result<(Self, Self), ErrorCode> {
+        if self.scale != rhs.scale {
+            return Err(ErrorCode::DifferentScale);
+        }
+        if rhs.is_zero() {
+            return Err(ErrorCode::DivisionByZero);
+        }
+
+        let (mut quotient, mut remainder) = (self.value / rhs.value, self.value % rhs.value);
+        if self.negative && !remainder.is_zero() {
+            quotient = quotient + T::from_small(1);
+            remainder = rhs.value - remainder;
+        }
+
+        Ok((
+            Self::new(
+                quotient
+                    .checked_mul(self.denominator())
+                    .ok_or(ErrorCode::ExceedsRange)?,
+                self.scale,
+                self.negative != rhs.negative,
+            ),
+            Self::new(remainder, self.scale, false),
+        ))
+    }
+}
+
+impl BigDecimal {
     pub fn zero() -> Self {
         Self {
             value: U192(BIG_ZERO),
@@ -119,71 +423,72 @@ impl BigDecimal {
         }
     }
 
+    /// Create a [BigDecimal] from an unsigned integer, assumed positive by default.
+    pub fn from_u64(integer: u64) -> Self {
+        BigDecimal {
+            value: integer.into(),
+            ..BigDecimal::default()
+        }
+    }
+
     /// Create a [BigDecimal] from an unsigned integer, assumed positive by default.
     pub fn from_u128(integer: u128) -> Self {
         BigDecimal {
-            value: U192::try_from(integer).unwrap_or_else(|_| {
-                panic!("decimal: value does not fit in BigDecimal::from_u128()")
-            }),
+            value: U192::from(integer),
             scale: 0,
             ..BigDecimal::default()
         }
     }
 
-    /// Show the scale of a [BigDecimal] expressed as a power of 10.
-    pub fn denominator(self) -> U192 {
-        U192::from(10u128.pow(self.scale.into()))
+    /// Computes the absolute value of a [BigDecimal] as a u64 and round down (floor) the value.
+    pub fn abs_u64(self) -> u64 {
+        u64::try_from(self.to_scale(0).value)
+            .unwrap_or_else(|_| panic!("decimal: overflow in abs_u64, value does not fit in u64"))
     }
 
-    /// Modify the scale (precision) of a [BigDecimal] to a different scale.
-    pub fn to_scale(self, scale: u8) -> Self {
-        Self {
-            value: match self.scale.cmp(&scale) {
-                Ordering::Equal => self.value,
-                Ordering::Greater => self
-                    .value
-                    .checked_div(U192::from(
-                        10u128.pow((self.scale.checked_sub(scale).unwrap()).into()),
-                    ))
-                    .expect("scaled_down"),
-                _ => self
-                    .value
-                    .checked_mul(U192::from(
-                        10u128.pow((scale.checked_sub(self.scale).unwrap()).into()),
-                    ))
-                    .expect("scaled_up"),
-            },
+    /// Computes the absolute value of a [BigDecimal] as a u64 and round up (ceiling) the value.
+    pub fn abs_u64_up(self) -> u64 {
+        u64::try_from(self.to_scale_up(0).value).unwrap_or_else(|_| {
+            panic!("decimal: overflow in abs_u64_up, value does not fit in u64")
+        })
+    }
+
+    /// Computes the absolute value of a [BigDecimal] and round up (ceiling) the value.
+    #[deprecated(note = "renamed to `abs_u64_up`, next to `abs_u64`")]
+    pub fn abs_up(self) -> u64 {
+        self.abs_u64_up()
+    }
+
+    /// Create a [BigDecimal] from an unsigned amount with scale, assumed positive by default.
+    pub fn from_scaled_amount(amount: u64, scale: u8) -> Self {
+        BigDecimal {
+            value: amount.into(),
             scale,
-            negative: self.negative,
+            ..BigDecimal::default()
         }
     }
 
-    /// Convert to a higher precision compute scale
-    pub fn to_compute_scale(self) -> Self {
-        self.to_scale(BIG_COMPUTE_SCALE)
+    /// Convert a [BigDecimal] back to a scaled u64 amount.
+    pub fn to_scaled_amount(self, scale: u8) -> u64 {
+        u64::try_from(self.to_scale(scale).value).unwrap_or_else(|_| {
+            panic!("decimal: overflow in to_scaled_amount, value does not fit in u64")
+        })
     }
 
-    /// Returns true if [BigDecimal] is positive and false if the number is zero or negative.
-    pub fn is_positive(self) -> bool {
-        !self.negative && !self.value.is_zero()
+    /// Convert a [BigDecimal] back to a scaled u64 amount and round up (ceiling) the value.
+    pub fn to_scaled_amount_up(self, scale: u8) -> u64 {
+        u64::try_from(self.to_scale_up(scale).value).unwrap_or_else(|_| {
+            panic!("decimal: overflow in to_scaled_amount_up, value does not fit in u64")
+        })
     }
 
-    /// Returns true if [BigDecimal] is negative and false if the number is zero or positive.
-    pub fn is_negative(self) -> bool {
-        self.negative && !self.value.is_zero()
+    /// Convert to a higher precision compute scale
+    pub fn to_compute_scale(self) -> Self {
+        self.to_scale(BIG_COMPUTE_SCALE)
     }
 }
 
 impl Decimal {
-    /// Create a new [Decimal] from its value, scale and negative parts.
-    pub fn new(value: u128, scale: u8, negative: bool) -> Self {
-        Self {
-            value,
-            scale,
-            negative,
-        }
-    }
-
     pub fn zero() -> Self {
         Self {
             value: 0,
@@ -257,14 +562,22 @@ impl Decimal {
         }
     }
 
-    /// Computes the absolute value of a [Decimal] and round down (floor) the value.
-    pub fn abs(self) -> u64 {
-        self.to_scale(0).into()
+    /// Computes the absolute value of a [Decimal] as a u64 and round down (floor) the value.
+    pub fn abs_u64(self) -> u64 {
+        u64::from_u128(self.to_scale(0).value)
+            .unwrap_or_else(|| panic!("decimal: overflow in abs_u64, value does not fit in u64"))
+    }
+
+    /// Computes the absolute value of a [Decimal] as a u64 and round up (ceiling) the value.
+    pub fn abs_u64_up(self) -> u64 {
+        u64::from_u128(self.to_scale_up(0).value)
+            .unwrap_or_else(|| panic!("decimal: overflow in abs_u64_up, value does not fit in u64"))
     }
 
     /// Computes the absolute value of a [Decimal] and round up (ceiling) the value.
+    #[deprecated(note = "renamed to `abs_u64_up`, next to `abs_u64`")]
     pub fn abs_up(self) -> u64 {
-        self.to_scale_up(0).into()
+        self.abs_u64_up()
     }
 
     /// Create a [Decimal] from an unsigned amount with scale, assumed positive by default.
@@ -278,49 +591,16 @@ impl Decimal {
 
     /// Convert a [Decimal] back to a scaled u64 amount.
     pub fn to_scaled_amount(self, scale: u8) -> u64 {
-        self.to_scale(scale).into()
+        u64::from_u128(self.to_scale(scale).value).unwrap_or_else(|| {
+            panic!("decimal: overflow in to_scaled_amount, value does not fit in u64")
+        })
     }
 
     /// Convert a [Decimal] back to a scaled u64 amount and round up (ceiling) the value.
     pub fn to_scaled_amount_up(self, scale: u8) -> u64 {
-        self.to_scale_up(scale).into()
-    }
-
-    /// Modify the scale (precision) of a [Decimal] to a different scale.
-    pub fn to_scale(self, scale: u8) -> Self {
-        Self {
-            value: match self.scale.cmp(&scale) {
-                Ordering::Equal => self.value,
-                Ordering::Greater => self
-                    .value
-                    .checked_div(10u128.pow((self.scale.checked_sub(scale).unwrap()).into()))
-                    .expect("scaled_down"),
-                _ => self
-                    .value
-                    .checked_mul(10u128.pow((scale.checked_sub(self.scale).unwrap()).into()))
-                    .expect("scaled_up"),
-            },
-            scale,
-            negative: self.negative,
-        }
-    }
-
-    /// Modify the scale (precision) of a [Decimal] to a different scale and round up (ceiling) the value.
-    pub fn to_scale_up(self, scale: u8) -> Self {
-        let decimal = Self::new(self.value, scale, self.negative);
-        if self.scale >= scale {
-            decimal.div_up(Self::new(
-                10u128.pow((self.scale.checked_sub(scale).unwrap()).try_into().unwrap()),
-                0,
-                self.negative,
-            ))
-        } else {
-            decimal.mul_up(Self::new(
-                10u128.pow((scale.checked_sub(self.scale).unwrap()).try_into().unwrap()),
-                0,
-                self.negative,
-            ))
-        }
+        u64::from_u128(self.to_scale_up(scale).value).unwrap_or_else(|| {
+            panic!("decimal: overflow in to_scaled_amount_up, value does not fit in u64")
+        })
     }
 
     /// Convert to a higher precision compute scale
@@ -328,193 +608,40 @@ impl Decimal {
         self.to_scale(COMPUTE_SCALE)
     }
 
-    /// Show the scale of a [Decimal] expressed as a power of 10.
-    pub fn denominator(self) -> u128 {
-        10u128.pow(self.scale.into())
-    }
-
-    /// Returns bit length of [Decimal] u128 value.
-    pub fn bit_length(self) -> u32 {
-        match 128u32.checked_sub(self.value.leading_zeros()) {
-            Some(bit_length) => bit_length,
-            None => panic!("decimal: overflow in Decimal bit_length"),
-        }
-    }
-
-    /// Returns true if [Decimal] is positive and false if the number is zero or negative.
-    pub fn is_positive(self) -> bool {
-        !self.negative && !self.is_zero()
-    }
-
-    /// Returns true if [Decimal] is negative and false if the number is zero or positive.
-    pub fn is_negative(self) -> bool {
-        self.negative && !self.is_zero()
-    }
-
-    /// Returns true if [Decimal] value is zero.
-    pub fn is_zero(self) -> bool {
-        self.value == 0
-    }
-
-    /// Returns true if and only if the [Decimal] is an exact integer.
-    pub fn is_integer(self) -> bool {
-        let integer = self.to_scale(0).to_scale(self.scale);
-
-        self.sub(integer).expect("zero").is_zero()
-    }
-
     /// Converts a string slice in a given base to a [Decimal].
-    /// The string is expected to be an optional - sign followed by digits.
-    /// Leading and trailing whitespace represent an error.
-    /// Digits are a subset of these characters, depending on radix.
-    /// This function panics if radix is not in the range base 10.
+    /// The string is expected to be an optional + or - sign followed by digits, with an
+    /// optional decimal point, `_` separators between digits and an (e|E) exponent.
+    /// Separators are not allowed in the exponent, and leading and trailing whitespace
+    /// represent an error.
+    /// The exponent shifts the digits instead of setting the scale, so `1.5e6` parses as
+    /// 1500000 at scale 0 and `1.5e-6` as 15 at scale 7.
+    /// Errors carry the position of the offending character, and the value and scale must
+    /// fit in a u128 and [MAX_SCALE] respectively.
     fn from_str_radix(s: &str, radix: u32) -> Result<Decimal, ErrorCode> {
         if radix != 10 {
             return Err(ErrorCode::ParseErrorBase10);
         }
 
-        let exp_separator: &[_] = &['e', 'E'];
-
-        // split slice into base and exponent parts
-        let (base, exp) = match s.find(exp_separator) {
-            // exponent defaults to 0 if (e|E) not found
-            None => (s, 0),
-
-            // split and parse exponent field
-            Some(loc) => {
-                // slice up to `loc` and 1 after to skip the 'e' char
-                let (base, exp) = (&s[..loc], &s[loc + 1..]);
-                (base, i64::from_str(exp).unwrap())
-            }
-        };
-
-        if base.is_empty() {
-            return Err(ErrorCode::ParseErrorEmpty);
-        }
-
-        // look for signed (negative) decimals
-        let (base, negative): (String, _) = match base.find('-') {
-            // no sign found, pass to Decimal
-            None => (base.to_string(), false),
-            Some(loc) => {
-                if loc == 0 {
-                    (String::from(&base[1..]), true)
-                } else {
-                    // negative sign not in the first position
-                    return Err(ErrorCode::ParseError);
-                }
-            }
-        };
-
-        // split decimal into a digit string and decimal-point offset
-        let (digits, decimal_offset): (String, _) = match base.find('.') {
-            // no decimal point found, pass directly to Decimal
-            None => (base.to_string(), 0),
-
-            // decimal point found - copy into new string buffer
-            Some(loc) => {
-                // split into leading and trailing digits
-                let (lead, trail) = (&base[..loc], &base[loc + 1..]);
-
-                // copy all leading characters into 'digits' string
-                let mut digits = String::from(lead);
-
-                // copy all trailing characters after '.' into the digits string
-                digits.push_str(trail);
-
-                (digits, trail.len() as i64)
-            }
-        };
-
-        let scale = (decimal_offset - exp).abs() as u8;
-
-        if exp.is_positive() {
-            Ok(Decimal::new(
-                Decimal::from_str(base.as_str())
-                    .expect("decimal of base")
-                    .to_scale(exp.abs() as u8)
-                    .value,
-                0,
-                negative,
-            )
-                .to_scale(exp.abs() as u8))
-        } else {
-            Ok(Decimal::new(
-                u128::from_str_radix(&digits, radix).unwrap(),
-                scale,
-                negative,
-            ))
-        }
-    }
-}
-
-impl From<Decimal> for u64 {
-    fn from(decimal: Decimal) -> u64 {
-        u64::from_u128(decimal.value).unwrap_or_else(|| {
-            panic!("decimal: overflow in From<Decimal> for u64, value does not fit")
-        })
-    }
-}
-
-impl From<Decimal> for u128 {
-    fn from(decimal: Decimal) -> u128 {
-        decimal.value
-    }
-}
-
-impl From<Decimal> for usize {
-    fn from(decimal: Decimal) -> usize {
-        usize::from_u128(decimal.value).unwrap_or_else(|| {
-            panic!("decimal: overflow in From<Decimal> for usize, value does not fit")
-        })
-    }
-}
-
-impl From<Decimal> for f64 {
-    fn from(decimal: Decimal) -> f64 {
-        let numerator = f64::from_u128(decimal.value).unwrap_or_else(|| {
-            panic!("decimal: overflow in From<Decimal> for f64, numerator does not fit")
-        });
-        let denominator = f64::from_u128(decimal.denominator()).unwrap_or_else(|| {
-            panic!("decimal: overflow in From<Decimal> for f64, denominator does not fit")
-        });
-        numerator / denominator
-    }
-}
-
-impl From<Decimal> for i32 {
-    fn from(decimal: Decimal) -> i32 {
-        let sign = if decimal.negative { -1i32 } else { 1i32 };
-        let value = i32::from_u128(decimal.value).unwrap_or_else(|| {
-            panic!("decimal: overflow in From<Decimal> for f64, value does not fit")
-        });
-
-        value
-            .checked_mul(sign)
-            .unwrap_or_else(|| panic!("decimal: overflow in From<Decimal> for i32, checked_mul"))
+        Decimal::from_ascii(s.as_bytes())
     }
 }
 
 impl From<Decimal> for BigDecimal {
     fn from(decimal: Decimal) -> BigDecimal {
-        BigDecimal {
-            value: U192::from(decimal.value),
-            scale: decimal.scale,
-            negative: decimal.negative,
-        }
+        BigDecimal::new(U192::from(decimal.value), decimal.scale, decimal.negative)
     }
 }
 
 impl From<BigDecimal> for Decimal {
     fn from(big_decimal: BigDecimal) -> Decimal {
-        Decimal {
-            value: big_decimal
+        Decimal::new(
+            big_decimal
                 .value
                 .try_into()
                 .unwrap_or_else(|_| panic!("decimal: value does not fit in BigDecimal::from()")),
-            scale: big_decimal.scale,
-            negative: big_decimal.negative,
-        }
+            big_decimal.scale,
+            big_decimal.negative,
+        )
     }
 }
 
@@ -527,45 +654,24 @@ impl FromStr for Decimal {
     }
 }
 
-impl fmt::Display for Decimal {
-    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let scale = self.scale as usize;
-        let mut rep = self.value.to_string();
-        let len = rep.len();
-
-        // inject decimal point
-        if scale > 0 {
-            match scale.cmp(&len) {
-                Ordering::Greater => {
-                    let mut new_rep = String::new();
-                    let zeros = "0".repeat(scale as usize - len);
-                    new_rep.push_str("0.");
-                    new_rep.push_str(&zeros[..]);
-                    new_rep.push_str(&rep[..]);
-                    rep = new_rep;
-                }
-                Ordering::Equal => {
-                    rep.insert(0, '.');
-                    rep.insert(0, '0');
-                }
-                _ => {
-                    rep.insert(len - scale as usize, '.');
-                }
-            }
-        } else if rep.is_empty() {
-            // corner case for truncated decimals
-            rep.insert(0, '0');
-        }
+impl FromStr for BigDecimal {
+    type Err = ErrorCode;
 
-        f.pad_integral(!self.negative, "", &rep)
+    /// Parses the same grammar as [Decimal], for the full U192 range at scales up to
+    /// [BIG_MAX_SCALE].
+    #[inline]
+    fn from_str(s: &str) -> Result<BigDecimal, ErrorCode> {
+        BigDecimal::from_ascii(s.as_bytes())
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::decimal::core::uint::U192;
+    use crate::decimal::core::RoundingMode;
+    use crate::decimal::errors::ErrorCode;
     use crate::decimal::ops::{Add, Div, DivUp, Mul, Pow, Sqrt, Sub};
-    use crate::decimal::{BigDecimal, Decimal};
+    use crate::decimal::{BigDecimal, Decimal, Decimal64};
     use proptest::prelude::*;
     use std::str::FromStr;
 
@@ -803,8 +909,8 @@ mod test {
 
     #[test]
     fn test_to_u64() {
-        let decimal = Decimal::new(69420, 6, false);
-        let actual: u64 = decimal.into();
+        let decimal = Decimal::new(69420_000000, 6, false);
+        let actual: u64 = decimal.try_into().unwrap();
         let expected: u64 = 69420;
 
         assert_eq!(actual, expected);
@@ -812,23 +918,35 @@ mod test {
 
     #[test]
     fn test_abs() {
+        let decimal = Decimal::new(4269420, 5, true);
+        assert_eq!(decimal.abs(), Decimal::new(4269420, 5, false));
+
+        let decimal = Decimal::new(4269420, 5, false);
+        assert_eq!(decimal.abs(), decimal);
+
+        let decimal = BigDecimal::new(U192::MAX, 0, true);
+        assert_eq!(decimal.abs(), BigDecimal::new(U192::MAX, 0, false));
+    }
+
+    #[test]
+    fn test_abs_u64() {
         let decimal = Decimal::new(0, 0, false);
-        assert_eq!(decimal.abs(), 0);
+        assert_eq!(decimal.abs_u64(), 0);
 
         let decimal = Decimal::new(42, 0, false);
-        assert_eq!(decimal.abs(), 42);
+        assert_eq!(decimal.abs_u64(), 42);
 
         let decimal = Decimal::new(4269420, 5, false);
-        assert_eq!(decimal.abs(), 42);
+        assert_eq!(decimal.abs_u64(), 42);
 
         let decimal = Decimal::new(4269420, 5, true);
-        assert_eq!(decimal.abs(), 42);
+        assert_eq!(decimal.abs_u64(), 42);
 
         let decimal = Decimal::new(4269420, 5, false);
-        assert_eq!(decimal.abs_up(), 43);
+        assert_eq!(decimal.abs_u64_up(), 43);
 
         let decimal = Decimal::new(4269420, 5, true);
-        assert_eq!(decimal.abs_up(), 43);
+        assert_eq!(decimal.abs_u64_up(), 43);
     }
 
     #[test]
@@ -885,14 +1003,188 @@ mod test {
             assert_eq!(result.scale, 0);
             assert_eq!({ result.value }, 1);
         }
+        // decrease precision keeps the sign
+        {
+            let decimal = Decimal::new(42, 2, true);
+            let result = decimal.to_scale_up(1);
+
+            assert_eq!(result, Decimal::new(5, 1, true));
+        }
+    }
+
+    #[test]
+    fn test_big_decimal_parity() {
+        // shared table of values checked against both Decimal and BigDecimal
+        let table = [
+            Decimal::new(0, 0, false),
+            Decimal::new(42, 0, false),
+            Decimal::new(42, 2, false),
+            Decimal::new(123, 4, false),
+            Decimal::new(4269420, 5, false),
+            Decimal::new(4269420, 5, true),
+            Decimal::new(42000, 3, true),
+            Decimal::new(u64::MAX as u128, 6, false),
+        ];
+
+        for decimal in table {
+            let big_decimal = BigDecimal::from(decimal);
+
+            assert_eq!(big_decimal.is_zero(), decimal.is_zero());
+            assert_eq!(big_decimal.is_positive(), decimal.is_positive());
+            assert_eq!(big_decimal.is_negative(), decimal.is_negative());
+            assert_eq!(big_decimal.is_integer(), decimal.is_integer());
+            assert_eq!(big_decimal.bit_length(), decimal.bit_length());
+            assert_eq!(big_decimal.abs(), BigDecimal::from(decimal.abs()));
+            assert_eq!(big_decimal.abs_u64(), decimal.abs_u64());
+            assert_eq!(big_decimal.abs_u64_up(), decimal.abs_u64_up());
+            assert_eq!(big_decimal.denominator(), U192::from(decimal.denominator()));
+
+            for scale in 0..=6 {
+                let up = big_decimal.to_scale_up(scale);
+                assert_eq!(up, BigDecimal::from(decimal.to_scale_up(scale)));
+                assert_eq!(up.scale, scale);
+                assert_eq!(
+                    big_decimal.to_scaled_amount(scale),
+                    decimal.to_scaled_amount(scale)
+                );
+                assert_eq!(
+                    big_decimal.to_scaled_amount_up(scale),
+                    decimal.to_scaled_amount_up(scale)
+                );
+            }
+        }
+
+        {
+            let big_decimal = BigDecimal::from_scaled_amount(4269420, 5);
+            assert_eq!(big_decimal, Decimal::from_scaled_amount(4269420, 5).into());
+            assert_eq!(big_decimal.scale, 5);
+
+            let big_decimal = BigDecimal::from_u64(42);
+            assert_eq!(big_decimal, Decimal::from_u64(42).into());
+            assert_eq!(big_decimal.scale, 0);
+        }
+    }
+
+    #[test]
+    fn test_big_decimal_beyond_u128_scale() {
+        {
+            // test: 1 at scale 57 has a denominator of 10^57
+            let one = BigDecimal::one().to_scale(57);
+            assert_eq!(one.denominator(), U192::from(10).pow(U192::from(57)));
+            assert_eq!(one.to_scale(0).value, U192::from(1));
+            assert!(one.is_integer());
+        }
+
+        {
+            // test: 10^-57 rounds up to 1
+            let smallest = BigDecimal::new(U192::from(1), 57, false);
+            assert_eq!(smallest.to_scale_up(0).value, U192::from(1));
+            assert!(!smallest.is_integer());
+        }
+
+        {
+            // test: U192::MAX = 192 bits
+            let decimal = BigDecimal::max_value(0);
+            assert_eq!(decimal.bit_length(), 192);
+        }
+    }
+
+    #[test]
+    fn test_decimal64() {
+        {
+            // test: 1.5 + 2.25 = 3.75
+            let a = Decimal64::new(150, 2, false);
+            let b = Decimal64::new(225, 2, false);
+            assert_eq!(a.add(b).unwrap(), Decimal64::new(375, 2, false));
+        }
+
+        {
+            // test: 4294967.296 * 4294967.296 = 18446744073709.551616, within u64 via u128
+            let a = Decimal64::new(4294967296, 3, false);
+            let actual = a.mul(a);
+            assert_eq!(actual, Decimal64::new(18446744073709551, 3, false));
+        }
+
+        {
+            // test: 1 / 3 = 0.333333
+            let a = Decimal64::new(1_000000, 6, false);
+            let b = Decimal64::new(3_000000, 6, false);
+            assert_eq!(a.div(b), Decimal64::new(333333, 6, false));
+        }
+
+        {
+            // test: sqrt(2) = 1.414213562
+            let two = Decimal64::new(2_000000000, 9, false);
+            assert_eq!(two.sqrt().unwrap(), Decimal64::new(1_414213562, 9, false));
+        }
+
+        {
+            // test: u64::MAX at scale 19 is the widest Decimal64
+            let decimal = Decimal64::max_value(19);
+            assert_eq!(decimal.denominator(), 10u64.pow(19));
+            assert_eq!(decimal.bit_length(), 64);
+            assert_eq!(decimal.to_scale(0).value, 1);
+        }
+    }
+
+    #[test]
+    fn test_to_scale_with() {
+        // increase precision
+        {
+            let decimal = Decimal::new(42, 2, false);
+            let result = decimal.to_scale_with(3, RoundingMode::HalfEven);
+
+            assert_eq!(result, Decimal::new(420, 3, false));
+        }
+        // banker's rounding: 0.125 = 0.12, 0.135 = 0.14
+        {
+            let a = Decimal::new(125, 3, false);
+            let b = Decimal::new(135, 3, false);
+
+            assert_eq!(
+                a.to_scale_with(2, RoundingMode::HalfEven),
+                Decimal::new(12, 2, false)
+            );
+            assert_eq!(
+                b.to_scale_with(2, RoundingMode::HalfEven),
+                Decimal::new(14, 2, false)
+            );
+        }
+        // floor and ceiling follow the sign: -0.42 = -0.5, -0.4
+        {
+            let decimal = Decimal::new(42, 2, true);
+
+            assert_eq!(
+                decimal.to_scale_with(1, RoundingMode::Floor),
+                Decimal::new(5, 1, true)
+            );
+            assert_eq!(
+                decimal.to_scale_with(1, RoundingMode::Ceiling),
+                Decimal::new(4, 1, true)
+            );
+        }
+        // big decimal: 2.5 = 3 with half up, 2 with half down
+        {
+            let decimal = BigDecimal::new(U192::from(25), 1, false);
+
+            assert_eq!(
+                decimal.to_scale_with(0, RoundingMode::HalfUp),
+                BigDecimal::new(U192::from(3), 0, false)
+            );
+            assert_eq!(
+                decimal.to_scale_with(0, RoundingMode::HalfDown),
+                BigDecimal::new(U192::from(2), 0, false)
+            );
+        }
     }
 
     #[test]
     fn test_into_u64() {
         {
-            let decimal = Decimal::new(333333333333333, 15, false);
-            let actual: u64 = decimal.into();
-            let expected: u64 = 333333333333333;
+            // test: the fraction is truncated, 3.333... = 3
+            let decimal = Decimal::new(3333333333333333, 15, false);
+            let actual: u64 = decimal.try_into().unwrap();
+            let expected: u64 = 3;
 
             assert_eq!(actual, expected);
         }
@@ -931,30 +1223,71 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_big_decimal_string() {
+        {
+            let decimal = BigDecimal::new(U192::MAX, 0, false);
+            let expected = "6277101735386680763835789423207666416102355444464034512895";
+            assert_eq!(decimal.to_string(), expected);
+            assert_eq!(BigDecimal::from_str(expected).unwrap(), decimal);
+        }
+
+        {
+            let decimal = BigDecimal::new(U192::from(15), 60, true);
+            let expected = "-0.000000000000000000000000000000000000000000000000000000000015";
+            assert_eq!(decimal.to_string(), expected);
+            // error: a scale above 57 is displayed, but not parsed back
+            assert_eq!(
+                BigDecimal::from_str(expected),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+        }
+
+        {
+            let decimal = BigDecimal::new(U192::from(2_718281828459045235u128), 18, false);
+            assert_eq!(decimal.to_string(), "2.718281828459045235");
+            assert_eq!(format!("{:>22}", decimal), "  2.718281828459045235");
+        }
+
+        {
+            let result =
+                BigDecimal::from_str("6277101735386680763835789423207666416102355444464034512896");
+            assert_eq!(result, Err(ErrorCode::ExceedsRange));
+        }
+    }
+
     #[test]
     fn test_from_string() {
         {
             let actual = Decimal::from_str("1e6").unwrap();
-            let expected = Decimal::new(1_000_000_000_000, 6, false);
+            let expected = Decimal::new(1_000_000, 0, false);
             assert_eq!(actual, expected);
+            assert_eq!(actual.value, 1_000_000);
+            assert_eq!(actual.scale, 0);
         }
 
         {
             let actual = Decimal::from_str("1.5e6").unwrap();
-            let expected = Decimal::new(1_500_000_000_000, 6, false);
+            let expected = Decimal::new(1_500_000, 0, false);
             assert_eq!(actual, expected);
+            assert_eq!(actual.value, 1_500_000);
+            assert_eq!(actual.scale, 0);
         }
 
         {
             let actual = Decimal::from_str("-1.5e6").unwrap();
-            let expected = Decimal::new(1_500_000_000_000, 6, true);
+            let expected = Decimal::new(1_500_000, 0, true);
             assert_eq!(actual, expected);
+            assert_eq!(actual.value, 1_500_000);
+            assert_eq!(actual.scale, 0);
         }
 
         {
             let actual = Decimal::from_str("1.5e9").unwrap();
-            let expected = Decimal::new(1_500_000_000_000_000_000, 9, false);
+            let expected = Decimal::new(1_500_000_000, 0, false);
             assert_eq!(actual, expected);
+            assert_eq!(actual.value, 1_500_000_000);
+            assert_eq!(actual.scale, 0);
         }
 
         {
@@ -992,14 +1325,58 @@ mod test {
             let expected = Decimal::new(42_500_420, 6, false);
             assert_eq!(actual, expected);
         }
+
+        {
+            let actual = Decimal::from_str("+1_000.000_5e-2").unwrap();
+            let expected = Decimal::new(10_000_005, 6, false);
+            assert_eq!(actual, expected);
+            assert_eq!(actual.value, 10_000_005);
+            assert_eq!(actual.scale, 6);
+        }
+
+        {
+            let actual = Decimal::from_str("1.2345678e6").unwrap();
+            let expected = Decimal::new(12_345_678, 1, false);
+            assert_eq!(actual, expected);
+            assert_eq!(actual.value, 12_345_678);
+            assert_eq!(actual.scale, 1);
+        }
     }
 
     #[test]
-    #[should_panic]
-    #[allow(unused_variables)]
-    fn test_into_u64_panic() {
+    fn test_from_string_errors() {
+        assert_eq!(
+            Decimal::from_str("1e1_0"),
+            Err(ErrorCode::ParseErrorSeparator { position: 3 })
+        );
+        assert_eq!(
+            Decimal::from_str("1e"),
+            Err(ErrorCode::ParseErrorMissingDigits { position: 2 })
+        );
+        assert_eq!(
+            Decimal::from_str("1.2x"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 3 })
+        );
+        assert_eq!(
+            Decimal::from_str("-1.5-"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 4 })
+        );
+        assert_eq!(
+            Decimal::from_str("1e-300"),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+        assert_eq!(
+            Decimal::from_str("340282366920938463463374607431768211456"),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(Decimal::from_str(""), Err(ErrorCode::ParseErrorEmpty));
+    }
+
+    #[test]
+    fn test_into_u64_overflow() {
         let decimal = Decimal::new(u128::MAX - 1, 15, false);
-        let result: u64 = decimal.into();
+        let result: Result<u64, ErrorCode> = decimal.try_into();
+        assert_eq!(result, Err(ErrorCode::ExceedsRange));
     }
 
     #[test]
@@ -1038,6 +1415,261 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_integer_part() {
+        {
+            // test: 2.5 and -2.5 at scale 1
+            let positive = Decimal::new(25, 1, false);
+            let negative = Decimal::new(25, 1, true);
+
+            assert_eq!(positive.trunc(), Decimal::new(20, 1, false));
+            assert_eq!(negative.trunc(), Decimal::new(20, 1, true));
+            assert_eq!(positive.floor().unwrap(), Decimal::new(20, 1, false));
+            assert_eq!(negative.floor().unwrap(), Decimal::new(30, 1, true));
+            assert_eq!(positive.ceil().unwrap(), Decimal::new(30, 1, false));
+            assert_eq!(negative.ceil().unwrap(), Decimal::new(20, 1, true));
+            assert_eq!(positive.fract(), Decimal::new(5, 1, false));
+            assert_eq!(negative.fract(), Decimal::new(5, 1, true));
+        }
+
+        {
+            // test: -0.4 truncates and ceils to zero, never negative zero
+            let decimal = Decimal::new(4, 1, true);
+
+            assert_eq!(decimal.trunc(), Decimal::new(0, 1, false));
+            assert!(!decimal.ceil().unwrap().negative);
+            assert_eq!(decimal.floor().unwrap(), Decimal::new(10, 1, true));
+        }
+
+        {
+            // test: integers are unchanged
+            let decimal = BigDecimal::new(U192::from(3_000u128), 3, true);
+
+            assert_eq!(decimal.floor().unwrap(), decimal);
+            assert_eq!(decimal.ceil().unwrap(), decimal);
+            assert!(decimal.fract().is_zero());
+        }
+
+        {
+            // test: -1234.5678 at scale 4
+            let decimal = BigDecimal::new(U192::from(12345678u128), 4, true);
+
+            assert_eq!(
+                decimal.floor().unwrap(),
+                BigDecimal::new(U192::from(12350000u128), 4, true)
+            );
+            assert_eq!(
+                decimal.ceil().unwrap(),
+                BigDecimal::new(U192::from(12340000u128), 4, true)
+            );
+            assert_eq!(
+                decimal.fract(),
+                BigDecimal::new(U192::from(5678u128), 4, true)
+            );
+        }
+    }
+
+    #[test]
+    fn test_round() {
+        {
+            // test: ties round away from zero, 2.5 -> 3, -2.5 -> -3
+            assert_eq!(
+                Decimal::new(25, 1, false).round(0).unwrap(),
+                Decimal::new(30, 1, false)
+            );
+            assert_eq!(
+                Decimal::new(25, 1, true).round(0).unwrap(),
+                Decimal::new(30, 1, true)
+            );
+        }
+
+        {
+            // test: 1.2345 to 2 decimal places = 1.2300, -1.2351 = -1.2400
+            assert_eq!(
+                Decimal::new(12345, 4, false).round(2).unwrap(),
+                Decimal::new(12300, 4, false)
+            );
+            assert_eq!(
+                Decimal::new(12351, 4, true).round(2).unwrap(),
+                Decimal::new(12400, 4, true)
+            );
+        }
+
+        {
+            // test: rounding to the scale or beyond is a no-op
+            let decimal = BigDecimal::new(U192::from(12345u128), 4, true);
+            assert_eq!(decimal.round(4).unwrap(), decimal);
+            assert_eq!(decimal.round(10).unwrap(), decimal);
+        }
+
+        {
+            // test: -0.4 rounds to zero, never negative zero
+            let actual = Decimal::new(4, 1, true).round(0).unwrap();
+            assert!(actual.is_zero() && !actual.negative);
+        }
+    }
+
+    #[test]
+    fn test_rounding_near_max() {
+        {
+            // test: rounding away from zero past the backing integer is an error, not a panic
+            let positive = Decimal::new(u128::MAX, 2, false);
+            let negative = Decimal::new(u128::MAX, 2, true);
+
+            assert_eq!(positive.ceil(), Err(ErrorCode::ExceedsRange));
+            assert_eq!(negative.floor(), Err(ErrorCode::ExceedsRange));
+            assert_eq!(positive.round(1), Err(ErrorCode::ExceedsRange));
+            assert_eq!(negative.round(0), Err(ErrorCode::ExceedsRange));
+        }
+
+        {
+            // test: rounding towards zero always fits
+            let positive = Decimal::new(u128::MAX, 2, false);
+            let negative = Decimal::new(u128::MAX, 2, true);
+            let integer = u128::MAX - u128::MAX % 100;
+
+            assert_eq!(positive.floor(), Ok(Decimal::new(integer, 2, false)));
+            assert_eq!(negative.ceil(), Ok(Decimal::new(integer, 2, true)));
+            assert_eq!(positive.trunc(), Decimal::new(integer, 2, false));
+            assert_eq!(positive.fract(), Decimal::new(u128::MAX % 100, 2, false));
+        }
+
+        {
+            // test: the largest value that still rounds up, at the last scale of the backing type
+            let decimal = BigDecimal::new(U192::MAX - U192::from(10u8), 1, false);
+            let expected = BigDecimal::new(U192::MAX - U192::MAX % U192::from(10u8), 1, false);
+
+            assert_eq!(decimal.ceil(), Ok(expected));
+            assert_eq!(decimal.round(0), Ok(expected));
+            assert_eq!(
+                BigDecimal::max_value(1).round(1),
+                Ok(BigDecimal::max_value(1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_signum_clamp_abs_diff() {
+        {
+            // test: signum at the scale of the number
+            assert_eq!(
+                Decimal::new(42, 2, true).signum(),
+                Decimal::new(100, 2, true)
+            );
+            assert_eq!(
+                Decimal::new(42, 2, false).signum(),
+                Decimal::new(100, 2, false)
+            );
+            assert_eq!(
+                Decimal::new(0, 2, false).signum(),
+                Decimal::new(0, 2, false)
+            );
+        }
+
+        {
+            // test: clamp to [-1.00, 2.00]
+            let min = Decimal::new(100, 2, true);
+            let max = Decimal::new(200, 2, false);
+
+            assert_eq!(Decimal::new(150, 2, true).clamp(min, max), min);
+            assert_eq!(Decimal::new(250, 2, false).clamp(min, max), max);
+            assert_eq!(
+                Decimal::new(50, 2, true).clamp(min, max),
+                Decimal::new(50, 2, true)
+            );
+        }
+
+        {
+            // test: bounds are rescaled into the range, 1.23 clamped to [1.251, 1.259] = 1.26
+            let min = Decimal::new(1251, 3, false);
+            let max = Decimal::new(1259, 3, false);
+            let actual = Decimal::new(123, 2, false).clamp(min, max);
+
+            assert_eq!(actual, Decimal::new(126, 2, false));
+        }
+
+        {
+            // test: |-1.5 - 2.25| = 3.75 and |2.25 - -1.5| = 3.75
+            let a = BigDecimal::new(U192::from(150u128), 2, true);
+            let b = BigDecimal::new(U192::from(225u128), 2, false);
+            let expected = BigDecimal::new(U192::from(375u128), 2, false);
+
+            assert_eq!(a.abs_diff(b), Ok(expected));
+            assert_eq!(b.abs_diff(a), Ok(expected));
+        }
+
+        {
+            // error: abs_diff with a different scale
+            let a = Decimal::new(150, 2, true);
+            let b = Decimal::new(225, 3, false);
+            assert_eq!(a.abs_diff(b), Err(ErrorCode::DifferentScale));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal: min is greater than max in method Decimal::clamp()")]
+    fn test_clamp_panic() {
+        let min = Decimal::new(2, 0, false);
+        let max = Decimal::new(1, 0, false);
+        Decimal::new(0, 0, false).clamp(min, max);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let seven = Decimal::new(70, 1, false);
+        let two = Decimal::new(20, 1, false);
+
+        {
+            // test: 7 = 3 · 2 + 1
+            let (quotient, remainder) = seven.div_rem(two).unwrap();
+            assert_eq!(quotient, Decimal::new(30, 1, false));
+            assert_eq!(remainder, Decimal::new(10, 1, false));
+        }
+
+        {
+            // test: -7 = -4 · 2 + 1, whereas -7 % 2 = -1
+            let (quotient, remainder) = (-seven).div_rem(two).unwrap();
+            assert_eq!(quotient, Decimal::new(40, 1, true));
+            assert_eq!(remainder, Decimal::new(10, 1, false));
+            assert_eq!(-seven % two, Decimal::new(10, 1, true));
+        }
+
+        {
+            // test: 7 = -3 · -2 + 1 and -7 = 4 · -2 + 1
+            let (quotient, remainder) = seven.div_rem(-two).unwrap();
+            assert_eq!(quotient, Decimal::new(30, 1, true));
+            assert_eq!(remainder, Decimal::new(10, 1, false));
+
+            let (quotient, remainder) = (-seven).div_rem(-two).unwrap();
+            assert_eq!(quotient, Decimal::new(40, 1, false));
+            assert_eq!(remainder, Decimal::new(10, 1, false));
+        }
+
+        {
+            // test: -5.5 = -3 · 2 + 0.5 in BigDecimal
+            let a = BigDecimal::new(U192::from(55u128), 1, true);
+            let b = BigDecimal::new(U192::from(20u128), 1, false);
+            let (quotient, remainder) = a.div_rem(b).unwrap();
+
+            assert_eq!(quotient, BigDecimal::new(U192::from(30u128), 1, true));
+            assert_eq!(remainder, BigDecimal::new(U192::from(5u128), 1, false));
+        }
+
+        {
+            // error: different scale, division by zero and a quotient beyond the range
+            let zero = Decimal::new(0, 1, false);
+            assert_eq!(
+                seven.div_rem(Decimal::new(2, 0, false)),
+                Err(ErrorCode::DifferentScale)
+            );
+            assert_eq!(seven.div_rem(zero), Err(ErrorCode::DivisionByZero));
+            assert_eq!(
+                Decimal::max_value(1).div_rem(Decimal::new(1, 1, false)),
+                Err(ErrorCode::ExceedsRange)
+            );
+        }
+    }
+
     #[test]
     fn test_is_integer() {
         // when scale is zero
@@ -1062,7 +1694,187 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_negative_zero() {
+        {
+            // test: -0 is constructed as 0
+            let decimal = Decimal::new(0, 3, true);
+            assert!(!decimal.negative);
+
+            let big_decimal = BigDecimal::new(U192::zero(), 3, true);
+            assert!(!big_decimal.negative);
+        }
+
+        {
+            // test: -0.004 to scale 2 = 0.00
+            let decimal = Decimal::new(4, 3, true);
+            assert!(!decimal.to_scale(2).negative);
+            assert!(!decimal.checked_to_scale(2).unwrap().negative);
+            assert!(!decimal.to_scale_with(2, RoundingMode::HalfEven).negative);
+            assert!(!BigDecimal::from(decimal).to_scale(2).negative);
+        }
+
+        {
+            // test: normalize clears a negative zero built from the public fields
+            let decimal = Decimal {
+                value: 0,
+                scale: 3,
+                negative: true,
+            };
+            assert_eq!(decimal.normalize(), Decimal::new(0, 3, false));
+            assert!(!decimal.normalize().negative);
+
+            let big_decimal = BigDecimal {
+                value: U192::zero(),
+                scale: 3,
+                negative: true,
+            };
+            assert!(!big_decimal.normalize().negative);
+        }
+    }
+
+    #[test]
+    fn test_reduce() {
+        {
+            // test: 1.500 = 1.5
+            let reduced = Decimal::new(1500, 3, false).reduce();
+            assert_eq!((reduced.value, reduced.scale), (15, 1));
+
+            let reduced = BigDecimal::new(U192::from(1500), 3, false).reduce();
+            assert_eq!((reduced.value, reduced.scale), (U192::from(15), 1));
+        }
+
+        {
+            // test: -42.000 = -42
+            let reduced = Decimal::new(42000, 3, true).reduce();
+            assert_eq!(
+                (reduced.value, reduced.scale, reduced.negative),
+                (42, 0, true)
+            );
+        }
+
+        {
+            // test: 4200 (scale 0) keeps its integer zeros
+            let reduced = Decimal::new(4200, 0, false).reduce();
+            assert_eq!((reduced.value, reduced.scale), (4200, 0));
+        }
+
+        {
+            // test: -0.000 = 0
+            let reduced = Decimal {
+                value: 0,
+                scale: 3,
+                negative: true,
+            }
+            .reduce();
+            assert_eq!(
+                (reduced.value, reduced.scale, reduced.negative),
+                (0, 0, false)
+            );
+        }
+    }
+
     proptest! {
+        #[test]
+        fn test_reduce_minimum_scale(value: u128, scale in 0..=38u8, negative: bool) {
+            let decimal = Decimal {
+                value,
+                scale,
+                negative,
+            };
+            let reduced = decimal.reduce();
+
+            assert_eq!(reduced, decimal);
+            assert!(reduced.scale == 0 || !reduced.value.is_multiple_of(10));
+            assert!(!(reduced.negative && reduced.is_zero()));
+            assert_eq!(BigDecimal::from(decimal).reduce(), BigDecimal::from(reduced));
+        }
+
+        #[test]
+        fn test_to_scale_never_negative_zero(
+            value in 0..1_000_000_000u128,
+            scale in 0..=12u8,
+            negative: bool,
+            target in 0..=12u8,
+        ) {
+            let decimal = Decimal::new(value, scale, negative);
+
+            for actual in [
+                decimal.to_scale(target),
+                decimal.to_scale_up(target),
+                decimal.checked_to_scale(target).unwrap(),
+                decimal.to_scale_with(target, RoundingMode::HalfEven),
+            ] {
+                assert!(!(actual.negative && actual.is_zero()), "{:?}", actual);
+            }
+        }
+
+        #[test]
+        fn test_integer_part_identities(
+            value in 0..1_000_000_000_000u128,
+            scale in 0..=12u8,
+            negative: bool,
+            dp in 0..=12u8,
+        ) {
+            let decimal = Decimal::new(value, scale, negative);
+            let (floor, ceil, trunc) = (decimal.floor().unwrap(), decimal.ceil().unwrap(), decimal.trunc());
+
+            prop_assert_eq!(trunc.add(decimal.fract()).unwrap(), decimal);
+            prop_assert!(floor <= decimal && decimal <= ceil);
+            prop_assert!(floor.is_integer() && ceil.is_integer());
+            let step = if decimal.is_integer() { 0 } else { 1 };
+            prop_assert_eq!(ceil.sub(floor).unwrap(), Decimal::from_u64(step).to_scale(scale));
+
+            // the rounding error is at most half a unit in the last place kept
+            let rounded = decimal.round(dp).unwrap();
+            prop_assert!(rounded.reduce().scale <= dp);
+            prop_assert!(rounded.abs_diff(decimal).unwrap().to_scale(dp + 1).value <= 5);
+        }
+
+        #[test]
+        fn test_div_rem_identity(
+            lhs in 0..1_000_000_000_000u128,
+            rhs in 1..1_000_000u128,
+            lhs_negative: bool,
+            rhs_negative: bool,
+        ) {
+            let a = Decimal::new(lhs, 6, lhs_negative);
+            let b = Decimal::new(rhs, 6, rhs_negative);
+            let (quotient, remainder) = a.div_rem(b).unwrap();
+
+            prop_assert!(quotient.is_integer());
+            prop_assert!(!remainder.negative && remainder.value < rhs);
+            prop_assert_eq!(quotient.mul(b).add(remainder).unwrap(), a);
+        }
+
+        #[test]
+        fn test_from_string_never_panics(s in "[-+0-9._eE ]{0,48}") {
+            let _ = Decimal::from_str(&s);
+        }
+
+        #[test]
+        fn test_from_string_round_trip(value: u128, scale in 0..=38u8, negative: bool) {
+            let decimal = Decimal::new(value, scale, negative);
+            let parsed = Decimal::from_str(&decimal.to_string()).unwrap();
+
+            prop_assert_eq!(parsed.value, value);
+            prop_assert_eq!(parsed.scale, scale);
+        }
+
+        #[test]
+        fn test_big_decimal_string_round_trip(
+            limbs: [u64; 3],
+            scale in 0..=57u8,
+            negative: bool,
+        ) {
+            let decimal = BigDecimal::new(U192(limbs), scale, negative);
+            let parsed = BigDecimal::from_str(&decimal.to_string()).unwrap();
+
+            prop_assert_eq!(parsed.value, decimal.value);
+            prop_assert_eq!(parsed.scale, decimal.scale);
+            prop_assert_eq!(parsed.negative, decimal.negative);
+        }
+
         #[test]
         fn test_full_u64_range(
             lhs in 1_000_000..u64::MAX, // 1.000000 .. 18,446,744,073,709.551615
@@ -0,0 +1,426 @@
+//! Conversions between [Decimal] and the primitive numeric types.
+//!
+//! Integers always fit, so `From<integer> for Decimal` is infallible with a scale of 0.
+//! The reverse conversions are `TryFrom`, which convert the numeric value, truncating any
+//! fraction toward zero like `as` does for floats, and fail with [ErrorCode::ExceedsRange]
+//! when the integer part does not fit. Round first with [Decimal::to_scale_with] for any
+//! other rounding mode.
+//!
+//! Floats convert through their shortest decimal representation, the digits [Display]
+//! prints, so `0.1f64` becomes exactly `0.1`. `TryFrom<f64>` fails when those digits do not
+//! fit a [Decimal], use [Decimal::from_f64_with] to round to a scale instead.
+//!
+//! [Display]: core::fmt::Display
+
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::RoundingMode;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::{Decimal, MAX_SCALE};
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Decimal {
+            fn from(integer: $t) -> Decimal {
+                Decimal::new(integer as u128, 0, false)
+            }
+        }
+
+        impl TryFrom<Decimal> for $t {
+            type Error = ErrorCode;
+
+            fn try_from(decimal: Decimal) -> Result<$t, ErrorCode> {
+                let integer = to_unsigned(U192::from(decimal.value), decimal.scale, decimal.negative)?;
+                <$t>::try_from(integer).map_err(|_| ErrorCode::ExceedsRange)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Decimal {
+            fn from(integer: $t) -> Decimal {
+                Decimal::new(integer.unsigned_abs() as u128, 0, integer < 0)
+            }
+        }
+
+        impl TryFrom<Decimal> for $t {
+            type Error = ErrorCode;
+
+            fn try_from(decimal: Decimal) -> Result<$t, ErrorCode> {
+                let integer = to_signed(U192::from(decimal.value), decimal.scale, decimal.negative)?;
+                <$t>::try_from(integer).map_err(|_| ErrorCode::ExceedsRange)
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<Decimal> for f64 {
+    /// Rounds to the nearest f64, a [Decimal] is always within range.
+    fn from(decimal: Decimal) -> f64 {
+        to_float(decimal.value, decimal.scale, decimal.negative)
+    }
+}
+
+impl TryFrom<Decimal> for f32 {
+    type Error = ErrorCode;
+
+    /// Rounds to the nearest f32, failing when the value is beyond [f32::MAX].
+    fn try_from(decimal: Decimal) -> Result<f32, ErrorCode> {
+        let float: f32 = to_float(decimal.value, decimal.scale, decimal.negative);
+        if float.is_infinite() {
+            return Err(ErrorCode::ExceedsRange);
+        }
+        Ok(float)
+    }
+}
+
+impl TryFrom<f64> for Decimal {
+    type Error = ErrorCode;
+
+    fn try_from(float: f64) -> Result<Decimal, ErrorCode> {
+        from_float(FloatParts::new(float)?)
+    }
+}
+
+impl TryFrom<f32> for Decimal {
+    type Error = ErrorCode;
+
+    fn try_from(float: f32) -> Result<Decimal, ErrorCode> {
+        from_float(FloatParts::new(float)?)
+    }
+}
+
+impl Decimal {
+    /// Create a [Decimal] with the given scale from an f64, rounding its shortest decimal
+    /// representation with the given [RoundingMode], e.g. 2.675 rounds half up to 2.68.
+    pub fn from_f64_with(float: f64, scale: u8, mode: RoundingMode) -> Result<Decimal, ErrorCode> {
+        from_float_with(FloatParts::new(float)?, scale, mode)
+    }
+
+    /// Create a [Decimal] with the given scale from an f32, rounding its shortest decimal
+    /// representation with the given [RoundingMode].
+    pub fn from_f32_with(float: f32, scale: u8, mode: RoundingMode) -> Result<Decimal, ErrorCode> {
+        from_float_with(FloatParts::new(float)?, scale, mode)
+    }
+}
+
+/// Integer part of `value * 10^-scale`, truncated toward zero.
+fn integer_part(value: U192, scale: u8, negative: bool) -> U192 {
+    RoundingMode::TowardZero.div_pow10(value, scale.into(), negative)
+}
+
+/// Convert the integer part of `value * 10^-scale` to a u128.
+pub(crate) fn to_unsigned(value: U192, scale: u8, negative: bool) -> Result<u128, ErrorCode> {
+    let integer = integer_part(value, scale, negative);
+    if negative && !integer.is_zero() {
+        return Err(ErrorCode::ExceedsRange);
+    }
+    integer.try_into().map_err(|_| ErrorCode::ExceedsRange)
+}
+
+/// Convert the integer part of `value * 10^-scale` to an i128.
+pub(crate) fn to_signed(value: U192, scale: u8, negative: bool) -> Result<i128, ErrorCode> {
+    let integer: u128 = integer_part(value, scale, negative)
+        .try_into()
+        .map_err(|_| ErrorCode::ExceedsRange)?;
+    if negative && integer <= i128::MIN.unsigned_abs() {
+        Ok((integer as i128).wrapping_neg())
+    } else {
+        i128::try_from(integer).map_err(|_| ErrorCode::ExceedsRange)
+    }
+}
+
+/// Round `value * 10^-scale` to the nearest float, by writing it as digits with an exponent,
+/// e.g. `-15e-1`, which floats parse with correct rounding.
+pub(crate) fn to_float<F: FromStr>(value: impl fmt::Display, scale: u8, negative: bool) -> F {
+    let mut buf = Buffer::new();
+    let sign = if negative { "-" } else { "" };
+    write!(buf, "{}{}e-{}", sign, value, scale).expect("fits the buffer");
+    match buf.as_str().parse() {
+        Ok(float) => float,
+        Err(_) => unreachable!("valid float"),
+    }
+}
+
+/// A float as `mantissa * 10^exponent`, from its shortest decimal representation.
+pub(crate) struct FloatParts {
+    mantissa: u64,
+    exponent: i32,
+    pub(crate) negative: bool,
+}
+
+impl FloatParts {
+    pub(crate) fn new<F: fmt::LowerExp + Copy>(float: F) -> Result<FloatParts, ErrorCode> {
+        let mut buf = Buffer::new();
+        write!(buf, "{:e}", float).expect("fits the buffer");
+        let rep = buf.as_str();
+        if rep.contains("inf") || rep.contains("NaN") {
+            return Err(ErrorCode::NotFinite);
+        }
+
+        let (negative, rep) = match rep.strip_prefix('-') {
+            Some(rep) => (true, rep),
+            None => (false, rep),
+        };
+        let (digits, exponent) = rep.split_once('e').expect("exponent");
+        let mut exponent: i32 = exponent.parse().expect("exponent");
+        let mut mantissa = 0u64;
+        let mut fraction = false;
+        for byte in digits.bytes() {
+            if byte == b'.' {
+                fraction = true;
+                continue;
+            }
+            mantissa = mantissa * 10 + u64::from(byte - b'0');
+            if fraction {
+                exponent -= 1;
+            }
+        }
+
+        Ok(FloatParts {
+            mantissa,
+            exponent,
+            // negative zero is just zero
+            negative: negative && mantissa != 0,
+        })
+    }
+}
+
+fn from_float(parts: FloatParts) -> Result<Decimal, ErrorCode> {
+    let scale = if parts.exponent >= 0 {
+        0
+    } else {
+        u8::try_from(-parts.exponent)
+            .ok()
+            .filter(|scale| *scale <= MAX_SCALE)
+            .ok_or(ErrorCode::ExceedsPrecisionRange)?
+    };
+    from_float_with(parts, scale, RoundingMode::TowardZero)
+}
+
+fn from_float_with(parts: FloatParts, scale: u8, mode: RoundingMode) -> Result<Decimal, ErrorCode> {
+    if scale > MAX_SCALE {
+        return Err(ErrorCode::ExceedsPrecisionRange);
+    }
+
+    let value = scale_float(&parts, scale, mode)?
+        .try_into()
+        .map_err(|_| ErrorCode::ExceedsRange)?;
+    Ok(Decimal::new(value, scale, parts.negative && value != 0))
+}
+
+/// Value of a float at the given scale, rounded with the given [RoundingMode].
+pub(crate) fn scale_float(
+    parts: &FloatParts,
+    scale: u8,
+    mode: RoundingMode,
+) -> Result<U192, ErrorCode> {
+    let shift = parts.exponent + i32::from(scale);
+    if shift >= 0 {
+        U192::from(10)
+            .checked_pow(U192::from(shift))
+            .and_then(|factor| factor.checked_mul(U192::from(parts.mantissa)))
+            .ok_or(ErrorCode::ExceedsRange)
+    } else {
+        Ok(mode.div_pow10(
+            U192::from(parts.mantissa),
+            shift.unsigned_abs(),
+            parts.negative,
+        ))
+    }
+}
+
+/// Stack buffer for formatting numbers without allocating.
+struct Buffer {
+    bytes: [u8; 80],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Buffer {
+        Buffer {
+            bytes: [0; 80],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).expect("ascii")
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::RoundingMode;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::Decimal;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Decimal::from(42u8), Decimal::new(42, 0, false));
+        assert_eq!(Decimal::from(-42i16), Decimal::new(42, 0, true));
+        assert_eq!(Decimal::from(u128::MAX), Decimal::new(u128::MAX, 0, false));
+
+        // test: the magnitude of i128::MIN fits the unsigned value
+        let min = Decimal::from(i128::MIN);
+        assert_eq!(min.value, 1 << 127);
+        assert!(min.negative);
+        assert_eq!(i128::try_from(min), Ok(i128::MIN));
+    }
+
+    #[test]
+    fn test_to_integers() {
+        // test: truncated toward zero, -2.75 = -2
+        let decimal = Decimal::new(275, 2, true);
+        assert_eq!(i8::try_from(decimal), Ok(-2));
+        assert_eq!(i64::try_from(decimal), Ok(-2));
+
+        // test: round first for other modes
+        let rounded = decimal.to_scale_with(0, RoundingMode::HalfUp);
+        assert_eq!(i32::try_from(rounded), Ok(-3));
+
+        // test: a negative fraction truncates to unsigned zero
+        assert_eq!(u32::try_from(Decimal::new(5, 1, true)), Ok(0));
+
+        // test: scale beyond the digits of a u128
+        assert_eq!(u8::try_from(Decimal::new(u128::MAX, 200, false)), Ok(0));
+
+        // error: out of range
+        assert_eq!(
+            u8::try_from(Decimal::new(256, 0, false)),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(
+            i8::try_from(Decimal::new(129, 0, true)),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(
+            u64::try_from(Decimal::new(1, 0, true)),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(
+            i128::try_from(Decimal::new((1 << 127) + 1, 0, true)),
+            Err(ErrorCode::ExceedsRange)
+        );
+    }
+
+    #[test]
+    fn test_to_floats() {
+        assert_eq!(f64::from(Decimal::new(1, 1, false)), 0.1);
+        assert_eq!(f64::from(Decimal::new(15, 1, true)), -1.5);
+        assert_eq!(
+            f64::from(Decimal::new(u128::MAX, 0, false)),
+            u128::MAX as f64
+        );
+        assert_eq!(f64::from(Decimal::new(1, 200, false)), 1e-200);
+        assert_eq!(f32::try_from(Decimal::new(1, 1, false)), Ok(0.1f32));
+
+        // error: beyond f32::MAX
+        assert_eq!(
+            f32::try_from(Decimal::new(u128::MAX, 0, false)),
+            Err(ErrorCode::ExceedsRange)
+        );
+    }
+
+    #[test]
+    fn test_from_floats() {
+        assert_eq!(Decimal::try_from(0.1f64), Ok(Decimal::new(1, 1, false)));
+        assert_eq!(Decimal::try_from(0.1f32), Ok(Decimal::new(1, 1, false)));
+        assert_eq!(Decimal::try_from(-1.5f64), Ok(Decimal::new(15, 1, true)));
+        assert_eq!(
+            Decimal::try_from(1e20f64),
+            Ok(Decimal::new(10u128.pow(20), 0, false))
+        );
+
+        // test: negative zero is zero
+        let zero = Decimal::try_from(-0.0f64).unwrap();
+        assert!(zero.is_zero() && !zero.negative);
+
+        // error: not representable
+        assert_eq!(Decimal::try_from(f64::NAN), Err(ErrorCode::NotFinite));
+        assert_eq!(Decimal::try_from(f64::INFINITY), Err(ErrorCode::NotFinite));
+        assert_eq!(Decimal::try_from(1e39f64), Err(ErrorCode::ExceedsRange));
+        assert_eq!(
+            Decimal::try_from(1e-39f64),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+    }
+
+    #[test]
+    fn test_from_floats_with() {
+        {
+            // test: rounds the shortest representation, 2.675 = 2.68
+            let decimal = Decimal::from_f64_with(2.675, 2, RoundingMode::HalfUp).unwrap();
+            assert_eq!((decimal.value, decimal.scale), (268, 2));
+        }
+
+        {
+            let decimal = Decimal::from_f64_with(-2.675, 1, RoundingMode::Floor).unwrap();
+            assert_eq!(
+                (decimal.value, decimal.scale, decimal.negative),
+                (27, 1, true)
+            );
+        }
+
+        {
+            // test: tiny values round to zero at the scale
+            let decimal = Decimal::from_f64_with(1e-300, 38, RoundingMode::HalfEven).unwrap();
+            assert!(decimal.is_zero() && !decimal.negative);
+        }
+
+        {
+            // test: pads to the scale
+            let decimal = Decimal::from_f32_with(1.5, 6, RoundingMode::TowardZero).unwrap();
+            assert_eq!((decimal.value, decimal.scale), (1_500_000, 6));
+        }
+
+        // error: scale above 38
+        assert_eq!(
+            Decimal::from_f64_with(1.0, 39, RoundingMode::TowardZero),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_i128_round_trip(integer: i128) {
+            prop_assert_eq!(i128::try_from(Decimal::from(integer)), Ok(integer));
+        }
+
+        #[test]
+        fn test_f64_round_trip(float in proptest::num::f64::NORMAL | proptest::num::f64::ZERO) {
+            if let Ok(decimal) = Decimal::try_from(float) {
+                prop_assert_eq!(f64::from(decimal), float);
+            }
+        }
+
+        #[test]
+        fn test_integer_conversions_never_panic(value: u128, scale: u8, negative: bool) {
+            let decimal = Decimal::new(value, scale, negative);
+            let _ = u8::try_from(decimal);
+            let _ = i64::try_from(decimal);
+            let _ = i128::try_from(decimal);
+            let _ = f32::try_from(decimal);
+            let _ = f64::from(decimal);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::GenericDecimal;
+
+/// Signed, scale-aware tolerance comparisons, meant for checking results against reference
+/// values. The operands may have different scales and are compared at the larger scale.
+/// An [ErrorCode::ExceedsRange] is returned when the aligned difference does not fit the
+/// [DecimalStorage::Wide] integer, rather than reporting a false match.
+pub trait ApproxEq<T>: Sized {
+    /// Show if |self - rhs| <= |tolerance|.
+    fn approx_eq_abs(self, rhs: T, tolerance: T) -> Result<bool, ErrorCode>;
+    /// Show if |self - rhs| <= |relative| * max(|self|, |rhs|).
+    fn approx_eq_rel(self, rhs: T, relative: T) -> Result<bool, ErrorCode>;
+    /// Show if self and rhs are at most `ulps` units in the last place apart, at the larger
+    /// scale of the two.
+    fn approx_eq_ulps(self, rhs: T, ulps: u128) -> Result<bool, ErrorCode>;
+}
+
+/// Compare two [GenericDecimal] values in the [DecimalStorage::Wide] integer, so operands
+/// that only fit once aligned, or whose difference exceeds the backing integer, still compare.
+impl<T: DecimalStorage> ApproxEq<GenericDecimal<T>> for GenericDecimal<T> {
+    fn approx_eq_abs(
+        self,
+        rhs: GenericDecimal<T>,
+        tolerance: GenericDecimal<T>,
+    ) -> Result<bool, ErrorCode> {
+        let scale = self.scale.max(rhs.scale).max(tolerance.scale);
+        let difference = abs_difference(self, rhs, scale)?;
+
+        Ok(difference <= widen(tolerance, scale)?)
+    }
+
+    fn approx_eq_rel(
+        self,
+        rhs: GenericDecimal<T>,
+        relative: GenericDecimal<T>,
+    ) -> Result<bool, ErrorCode> {
+        let scale = self.scale.max(rhs.scale);
+        let difference = abs_difference(self, rhs, scale)?;
+        let magnitude = widen(self, scale)?.max(widen(rhs, scale)?);
+
+        // compare difference <= relative * magnitude without losing the digits of relative
+        let lhs = T::Wide::checked_pow10(relative.scale.into())
+            .ok_or(ErrorCode::ExceedsRange)
+            .map(|factor| difference.checked_mul(factor))?;
+        let rhs = T::Wide::from(relative.value).checked_mul(magnitude);
+
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => Ok(lhs <= rhs),
+            // relative * magnitude beyond the wide integer bounds any representable difference
+            (Some(_), None) => Ok(true),
+            _ => Err(ErrorCode::ExceedsRange),
+        }
+    }
+
+    fn approx_eq_ulps(self, rhs: GenericDecimal<T>, ulps: u128) -> Result<bool, ErrorCode> {
+        let scale = self.scale.max(rhs.scale);
+        let difference = abs_difference(self, rhs, scale)?;
+
+        // a number of ulps beyond the wide integer bounds any difference
+        Ok(T::Wide::checked_from_u128(ulps).is_none_or(|ulps| difference <= ulps))
+    }
+}
+
+/// The value of a [GenericDecimal] rescaled to a scale at least as large as its own, in the
+/// wide integer.
+fn widen<T: DecimalStorage>(x: GenericDecimal<T>, scale: u8) -> Result<T::Wide, ErrorCode> {
+    x.widen(scale).ok_or(ErrorCode::ExceedsRange)
+}
+
+/// Magnitude of the signed difference of two values, rescaled to a common scale.
+fn abs_difference<T: DecimalStorage>(
+    lhs: GenericDecimal<T>,
+    rhs: GenericDecimal<T>,
+    scale: u8,
+) -> Result<T::Wide, ErrorCode> {
+    let (lhs_value, rhs_value) = (widen(lhs, scale)?, widen(rhs, scale)?);
+
+    if lhs.is_negative() != rhs.is_negative() {
+        lhs_value
+            .checked_add(rhs_value)
+            .ok_or(ErrorCode::ExceedsRange)
+    } else if lhs_value > rhs_value {
+        Ok(lhs_value - rhs_value)
+    } else {
+        Ok(rhs_value - lhs_value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::core::ApproxEq;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::{BigDecimal, Decimal, Decimal64};
+
+    #[test]
+    fn test_approx_eq_abs() {
+        let tolerance = Decimal::new(1, 3, false);
+
+        // test: |1.0005 - 1.000| <= 0.001
+        {
+            let a = Decimal::new(10005, 4, false);
+            let b = Decimal::new(1000, 3, false);
+            assert!(a.approx_eq_abs(b, tolerance).unwrap());
+        }
+
+        // test: |0.001 - 42| > 0.001, in either order
+        {
+            let small = Decimal::new(1, 3, false);
+            let big = Decimal::new(42, 0, false);
+            assert!(!small.approx_eq_abs(big, tolerance).unwrap());
+            assert!(!big.approx_eq_abs(small, tolerance).unwrap());
+        }
+
+        // test: |0.0005 - -0.0005| <= 0.001, but |0.0006 - -0.0005| > 0.001
+        {
+            let a = Decimal::new(5, 4, false);
+            let b = Decimal::new(5, 4, true);
+            let c = Decimal::new(6, 4, false);
+            assert!(a.approx_eq_abs(b, tolerance).unwrap());
+            assert!(!c.approx_eq_abs(b, tolerance).unwrap());
+        }
+
+        // test: U192::MAX at scale 0 is aligned to scale 3 in the wide integer
+        {
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::MAX, 0, false);
+            assert!(a.approx_eq_abs(b, tolerance.into()).unwrap());
+        }
+
+        // error: the difference of ±u64::MAX at scale 19 exceeds the wide u128
+        {
+            let a = Decimal64::new(u64::MAX, 0, false);
+            let b = Decimal64::new(u64::MAX, 0, true);
+            assert!(matches!(
+                a.approx_eq_abs(b, Decimal64::new(1, 19, false)),
+                Err(ErrorCode::ExceedsRange)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_approx_eq_rel() {
+        let relative = Decimal::new(1, 6, false);
+
+        // test: |1000000 - 1000001| <= 0.000001 * 1000001
+        {
+            let a = Decimal::new(1_000_000, 0, false);
+            let b = Decimal::new(1_000_001, 0, false);
+            assert!(a.approx_eq_rel(b, relative).unwrap());
+        }
+
+        // test: |1000000 - 1000002| > 0.000001 * 1000002
+        {
+            let a = Decimal::new(1_000_000, 0, false);
+            let b = Decimal::new(1_000_002, 0, false);
+            assert!(!a.approx_eq_rel(b, relative).unwrap());
+        }
+
+        // test: opposite signs are never relatively equal for a tolerance below 2
+        {
+            let a = Decimal::new(1_000_000, 0, false);
+            let b = Decimal::new(1_000_000, 0, true);
+            assert!(!a.approx_eq_rel(b, relative).unwrap());
+        }
+
+        // test: e^1 at scale 18 against a scale 12 reference
+        {
+            let a = BigDecimal::new(U192::from(2_718281828459045235u128), 18, false);
+            let b = BigDecimal::new(U192::from(2_718281828459u128), 12, false);
+            let tight = BigDecimal::new(U192::from(1), 14, false);
+            let loose = BigDecimal::new(U192::from(1), 12, false);
+            assert!(!a.approx_eq_rel(b, tight).unwrap());
+            assert!(a.approx_eq_rel(b, loose).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_approx_eq_ulps() {
+        // test: 1.000 and 1.0002 are 2 ulps apart at scale 4
+        {
+            let a = Decimal::new(1000, 3, false);
+            let b = Decimal::new(10002, 4, false);
+            assert!(a.approx_eq_ulps(b, 2).unwrap());
+            assert!(!a.approx_eq_ulps(b, 1).unwrap());
+        }
+
+        // test: -0.001 and 0.001 are 2 ulps apart
+        {
+            let a = BigDecimal::new(U192::from(1), 3, true);
+            let b = BigDecimal::new(U192::from(1), 3, false);
+            assert!(a.approx_eq_ulps(b, 2).unwrap());
+            assert!(!a.approx_eq_ulps(b, 1).unwrap());
+        }
+    }
+}
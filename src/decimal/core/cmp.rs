@@ -1,6 +1,11 @@
-use crate::decimal::Decimal;
+use crate::decimal::core::{ApproxEq, DecimalStorage};
 use crate::decimal::errors::ErrorCode;
+use crate::decimal::GenericDecimal;
+use core::cmp::Ordering;
 
+/// Comparison query operators between two values of the same scale, returning
+/// [ErrorCode::DifferentScale] otherwise. The results agree with [Ord], which also compares
+/// across scales and provides [Ord::min] and [Ord::max].
 pub trait Compare<T>: Sized {
     fn eq(self, rhs: T) -> Result<bool, ErrorCode>;
     fn almost_eq(self, rhs: T, precision: u128) -> Result<bool, ErrorCode>;
@@ -8,110 +13,62 @@ pub trait Compare<T>: Sized {
     fn gt(self, rhs: T) -> Result<bool, ErrorCode>;
     fn gte(self, rhs: T) -> Result<bool, ErrorCode>;
     fn lte(self, rhs: T) -> Result<bool, ErrorCode>;
-    fn min(self, rhs: T) -> Self;
-    fn max(self, rhs: T) -> Self;
 }
 
-/// Compare two [Decimal] values/scale with comparison query operators.
-impl Compare<Decimal> for Decimal {
-    /// Show if two [Decimal] values equal each other
-    fn eq(self, other: Decimal) -> Result<bool, ErrorCode> {
-        if self.scale != other.scale {
-            Err(ErrorCode::DifferentScale)
-        } else {
-            Ok(self.value == other.value && self.negative == other.negative)
-        }
+/// Order two [GenericDecimal] values of the same scale with [Ord::cmp].
+fn cmp_same_scale<T: DecimalStorage>(
+    lhs: GenericDecimal<T>,
+    rhs: GenericDecimal<T>,
+) -> Result<Ordering, ErrorCode> {
+    if lhs.scale != rhs.scale {
+        Err(ErrorCode::DifferentScale)
+    } else {
+        Ok(lhs.cmp(&rhs))
     }
+}
 
-    /// Show if two [Decimal] values are almost equal to each other, given a precision
-    fn almost_eq(self, other: Decimal, precision: u128) -> Result<bool, ErrorCode> {
-        let difference = self.value.saturating_sub(other.value);
-        Ok(difference.lt(&precision))
+/// Compare two [GenericDecimal] values/scale with comparison query operators.
+impl<T: DecimalStorage> Compare<GenericDecimal<T>> for GenericDecimal<T> {
+    /// Show if two [GenericDecimal] values equal each other
+    fn eq(self, other: GenericDecimal<T>) -> Result<bool, ErrorCode> {
+        Ok(cmp_same_scale(self, other)?.is_eq())
     }
 
-    /// Show if one [Decimal] value is less than another.
-    fn lt(self, other: Decimal) -> Result<bool, ErrorCode> {
+    /// Show if two [GenericDecimal] values are almost equal to each other, given a precision
+    /// as the signed difference in units of the scale which must be less than precision.
+    fn almost_eq(self, other: GenericDecimal<T>, precision: u128) -> Result<bool, ErrorCode> {
         if self.scale != other.scale {
             Err(ErrorCode::DifferentScale)
-        } else if self.negative && other.negative {
-            Ok(self.value > other.value)
-        } else if self.negative && !other.negative {
-            Ok(true)
-        } else if !self.negative && other.negative {
-            Ok(false)
         } else {
-            Ok(self.value < other.value)
+            Ok(precision > 0 && self.approx_eq_ulps(other, precision - 1)?)
         }
     }
 
-    /// Show if one [Decimal] value is greater than another.
-    fn gt(self, other: Decimal) -> Result<bool, ErrorCode> {
-        if self.scale != other.scale {
-            Err(ErrorCode::DifferentScale)
-        } else if self.negative && other.negative {
-            Ok(self.value < other.value)
-        } else if self.negative && !other.negative {
-            Ok(false)
-        } else if !self.negative && other.negative {
-            Ok(true)
-        } else {
-            Ok(self.value > other.value)
-        }
+    /// Show if one [GenericDecimal] value is less than another.
+    fn lt(self, other: GenericDecimal<T>) -> Result<bool, ErrorCode> {
+        Ok(cmp_same_scale(self, other)?.is_lt())
     }
 
-    /// Show if one [Decimal] value is greater than or equal to another.
-    fn gte(self, other: Decimal) -> Result<bool, ErrorCode> {
-        if self.scale != other.scale {
-            Err(ErrorCode::DifferentScale)
-        } else if self.negative && other.negative {
-            Ok(self.value <= other.value)
-        } else if self.negative && !other.negative {
-            Ok(false)
-        } else if !self.negative && other.negative {
-            Ok(true)
-        } else {
-            Ok(self.value >= other.value)
-        }
-    }
-
-    /// Show if one [Decimal] value is less than or equal to another.
-    fn lte(self, other: Decimal) -> Result<bool, ErrorCode> {
-        if self.scale != other.scale {
-            Err(ErrorCode::DifferentScale)
-        } else if self.negative && other.negative {
-            Ok(self.value >= other.value)
-        } else if self.negative && !other.negative {
-            Ok(true)
-        } else if !self.negative && other.negative {
-            Ok(false)
-        } else {
-            Ok(self.value <= other.value)
-        }
+    /// Show if one [GenericDecimal] value is greater than another.
+    fn gt(self, other: GenericDecimal<T>) -> Result<bool, ErrorCode> {
+        Ok(cmp_same_scale(self, other)?.is_gt())
     }
 
-    /// Show the minimum of two [Decimal] values.
-    fn min(self, other: Decimal) -> Decimal {
-        if self.lte(other).unwrap() {
-            self
-        } else {
-            other
-        }
+    /// Show if one [GenericDecimal] value is greater than or equal to another.
+    fn gte(self, other: GenericDecimal<T>) -> Result<bool, ErrorCode> {
+        Ok(cmp_same_scale(self, other)?.is_ge())
     }
 
-    /// Show the maximum of two [Decimal] values.
-    fn max(self, other: Decimal) -> Decimal {
-        if self.gte(other).unwrap() {
-            self
-        } else {
-            other
-        }
+    /// Show if one [GenericDecimal] value is less than or equal to another.
+    fn lte(self, other: GenericDecimal<T>) -> Result<bool, ErrorCode> {
+        Ok(cmp_same_scale(self, other)?.is_le())
     }
 }
 
 #[cfg(test)]
 mod test {
     use crate::decimal::core::Compare;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_lte() {
@@ -420,6 +377,61 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_negative_zero() {
+        // test: a negative zero built from the public fields agrees with Ord
+        let negative_zero = Decimal {
+            value: 0,
+            scale: 2,
+            negative: true,
+        };
+        let zero = Decimal::new(0, 2, false);
+
+        assert!(negative_zero.eq(zero).unwrap());
+        assert!(!negative_zero.lt(zero).unwrap());
+        assert!(!zero.gt(negative_zero).unwrap());
+        assert!(negative_zero.lte(zero).unwrap() && negative_zero.gte(zero).unwrap());
+
+        let negative_zero = BigDecimal::from(negative_zero);
+        let zero = BigDecimal::from(zero);
+        assert!(negative_zero.eq(zero).unwrap());
+        assert!(!negative_zero.lt(zero).unwrap());
+    }
+
+    #[test]
+    fn test_almost_eq() {
+        {
+            let decimal = Decimal::new(1001, 4, false);
+            let other = Decimal::new(1000, 4, false);
+
+            assert!(decimal.almost_eq(other, 2).unwrap());
+            assert!(!decimal.almost_eq(other, 1).unwrap());
+        }
+
+        {
+            let small = Decimal::new(1, 4, false);
+            let big = Decimal::new(42_0000, 4, false);
+
+            assert!(!small.almost_eq(big, 2).unwrap());
+            assert!(!big.almost_eq(small, 2).unwrap());
+        }
+
+        {
+            let decimal = Decimal::new(1, 4, false);
+            let other = Decimal::new(1, 4, true);
+
+            assert!(!decimal.almost_eq(other, 2).unwrap());
+            assert!(decimal.almost_eq(other, 3).unwrap());
+        }
+
+        {
+            let decimal = Decimal::new(1, 4, false);
+            let other = Decimal::new(1, 3, false);
+
+            assert!(decimal.almost_eq(other, 2).is_err());
+        }
+    }
+
     #[test]
     fn test_min_max() {
         {
@@ -453,5 +465,96 @@ mod test {
 
             assert_eq!(decimal, result);
         }
+
+        {
+            let decimal = Decimal::new(10, 2, false);
+            let other = Decimal::new(11, 3, false);
+            let result = decimal.min(other);
+
+            assert_eq!(other, result);
+        }
+    }
+
+    #[test]
+    fn test_compare_shared() {
+        // shared (lhs, rhs, [eq, lt, lte, gt, gte]) table for Decimal and BigDecimal
+        let table = [
+            (
+                Decimal::new(1001, 4, false),
+                Decimal::new(33, 4, false),
+                [false, false, false, true, true],
+            ),
+            (
+                Decimal::new(33, 4, false),
+                Decimal::new(33, 4, false),
+                [true, false, true, false, true],
+            ),
+            (
+                Decimal::new(10, 4, false),
+                Decimal::new(33, 4, false),
+                [false, true, true, false, false],
+            ),
+            (
+                Decimal::new(43, 0, true),
+                Decimal::new(42, 0, true),
+                [false, true, true, false, false],
+            ),
+            (
+                Decimal::new(42, 0, false),
+                Decimal::new(42, 0, true),
+                [false, false, false, true, true],
+            ),
+            (
+                Decimal::new(42, 0, true),
+                Decimal::new(42, 0, false),
+                [false, true, true, false, false],
+            ),
+        ];
+
+        for (decimal, other, expected) in table {
+            let actual = [
+                decimal.eq(other).unwrap(),
+                decimal.lt(other).unwrap(),
+                decimal.lte(other).unwrap(),
+                decimal.gt(other).unwrap(),
+                decimal.gte(other).unwrap(),
+            ];
+            assert_eq!(actual, expected);
+
+            let (decimal, other) = (BigDecimal::from(decimal), BigDecimal::from(other));
+            let actual = [
+                decimal.eq(other).unwrap(),
+                decimal.lt(other).unwrap(),
+                decimal.lte(other).unwrap(),
+                decimal.gt(other).unwrap(),
+                decimal.gte(other).unwrap(),
+            ];
+            assert_eq!(actual, expected);
+            assert_eq!(
+                decimal.min(other),
+                if expected[1] { decimal } else { other }
+            );
+            assert_eq!(
+                decimal.max(other),
+                if expected[3] { decimal } else { other }
+            );
+        }
+
+        {
+            // error: different scales
+            let decimal = BigDecimal::from(Decimal::new(1001, 4, false));
+            let other = BigDecimal::from(Decimal::new(33, 2, false));
+
+            assert!(decimal.lt(other).is_err());
+            assert!(decimal.almost_eq(other, 2).is_err());
+        }
+
+        {
+            let decimal = BigDecimal::from(Decimal::new(1001, 4, false));
+            let other = BigDecimal::from(Decimal::new(1000, 4, false));
+
+            assert!(decimal.almost_eq(other, 2).unwrap());
+            assert!(!decimal.almost_eq(other, 1).unwrap());
+        }
     }
 }
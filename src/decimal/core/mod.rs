@@ -1,4 +1,12 @@
+pub(crate) mod approx;
 pub(crate) mod cmp;
+pub(crate) mod ord;
+pub(crate) mod parse;
+pub(crate) mod rounding;
+pub(crate) mod storage;
 pub(crate) mod uint;
 
+pub use approx::*;
 pub use cmp::*;
+pub use rounding::*;
+pub use storage::*;
@@ -0,0 +1,179 @@
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Compare two unsigned magnitudes at possibly different scales. The value with the smaller
+/// scale is scaled up, and if that overflows it must be the larger of the two.
+fn cmp_magnitude<T: UnsignedInt>(lhs: T, lhs_scale: u8, rhs: T, rhs_scale: u8) -> Ordering {
+    let scale_up = |value: T, exponent: u8| {
+        if value.is_zero() {
+            Some(value)
+        } else {
+            T::checked_pow10(exponent.into()).and_then(|factor| value.checked_mul(factor))
+        }
+    };
+
+    match lhs_scale.cmp(&rhs_scale) {
+        Ordering::Equal => lhs.cmp(&rhs),
+        Ordering::Less => match scale_up(lhs, rhs_scale - lhs_scale) {
+            Some(lhs) => lhs.cmp(&rhs),
+            None => Ordering::Greater,
+        },
+        Ordering::Greater => match scale_up(rhs, lhs_scale - rhs_scale) {
+            Some(rhs) => lhs.cmp(&rhs),
+            None => Ordering::Less,
+        },
+    }
+}
+
+/// Numeric ordering of [GenericDecimal] values across signs and scales, e.g. -5 < 3 and
+/// 1.0 (scale 1) == 1 (scale 0) == -0.
+impl<T: DecimalStorage> Ord for GenericDecimal<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(self.value, self.scale, other.value, other.scale),
+            (true, true) => cmp_magnitude(other.value, other.scale, self.value, self.scale),
+        }
+    }
+}
+
+impl<T: DecimalStorage> PartialOrd for GenericDecimal<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: DecimalStorage> PartialEq for GenericDecimal<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: DecimalStorage> Eq for GenericDecimal<T> {}
+
+/// Hash the reduced form of a value, with trailing zeros removed and no negative zero, so
+/// numerically equal values hash equally.
+impl<T: DecimalStorage> Hash for GenericDecimal<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let reduced = self.reduce();
+
+        reduced.value.hash(state);
+        reduced.scale.hash(state);
+        reduced.negative.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_ord() {
+        // test: -5 < 3
+        assert!(Decimal::new(5, 0, true) < Decimal::new(3, 0, false));
+
+        // test: -5 < -3
+        assert!(Decimal::new(5, 0, true) < Decimal::new(3, 0, true));
+
+        // test: 0.99 < 1
+        assert!(Decimal::new(99, 2, false) < Decimal::new(1, 0, false));
+
+        // test: 1.0 (scale 1) == 1 (scale 0)
+        assert_eq!(Decimal::new(10, 1, false), Decimal::new(1, 0, false));
+
+        // test: -0 == 0
+        assert_eq!(Decimal::new(0, 3, true), Decimal::new(0, 0, false));
+
+        // test: u128::MAX > u128::MAX at scale 255
+        assert!(Decimal::new(u128::MAX, 0, false) > Decimal::new(u128::MAX, 255, false));
+
+        // test: 0.0...01 at scale 255 > 0
+        assert!(Decimal::new(1, 255, false) > Decimal::new(0, 0, false));
+
+        // test: -U192::MAX < -U192::MAX at scale 1
+        assert!(BigDecimal::new(U192::MAX, 0, true) < BigDecimal::new(U192::MAX, 1, true));
+    }
+
+    #[test]
+    fn test_sort_and_collections() {
+        let mut values = vec![
+            Decimal::new(3, 0, false),
+            Decimal::new(5, 0, true),
+            Decimal::new(150, 2, false),
+            Decimal::new(0, 1, true),
+            Decimal::new(15, 1, false),
+        ];
+        values.sort();
+
+        assert_eq!(
+            values,
+            vec![
+                Decimal::new(5, 0, true),
+                Decimal::new(0, 0, false),
+                Decimal::new(15, 1, false),
+                Decimal::new(15, 1, false),
+                Decimal::new(3, 0, false),
+            ]
+        );
+
+        // test: 1.50 and 1.5 are the same key
+        let tree: BTreeSet<Decimal> = values.iter().copied().collect();
+        let hash: HashSet<Decimal> = values.iter().copied().collect();
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(hash.len(), 4);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            hash_of(&Decimal::new(1_000, 3, false)),
+            hash_of(&Decimal::new(1, 0, false))
+        );
+        assert_eq!(
+            hash_of(&Decimal::new(0, 6, true)),
+            hash_of(&Decimal::new(0, 0, false))
+        );
+        assert_ne!(
+            hash_of(&Decimal::new(1, 0, true)),
+            hash_of(&Decimal::new(1, 0, false))
+        );
+        assert_eq!(
+            hash_of(&BigDecimal::new(U192::from(2_500), 3, false)),
+            hash_of(&BigDecimal::new(U192::from(25), 1, false))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_ord_matches_i128(
+            a in 0..1_000_000_000u128, a_scale in 0..9u8, a_negative: bool,
+            b in 0..1_000_000_000u128, b_scale in 0..9u8, b_negative: bool,
+        ) {
+            let x = Decimal::new(a, a_scale, a_negative);
+            let y = Decimal::new(b, b_scale, b_negative);
+            let signed = |value: u128, scale: u8, negative: bool| {
+                let value = (value * 10u128.pow((9 - scale).into())) as i128;
+                if negative { -value } else { value }
+            };
+            let expected = signed(a, a_scale, a_negative).cmp(&signed(b, b_scale, b_negative));
+
+            prop_assert_eq!(x.cmp(&y), expected);
+            prop_assert!(x != y || hash_of(&x) == hash_of(&y));
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::decimal::core::UnsignedInt;
+use crate::decimal::errors::ErrorCode;
+
+/// Sign, digits and scale of a parsed decimal string, before it is narrowed to a
+/// [GenericDecimal](crate::decimal::GenericDecimal) or a fixed point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Parsed<T> {
+    pub value: T,
+    pub scale: u8,
+    pub negative: bool,
+}
+
+/// Parse a decimal number from its bytes without allocating, never panicking on any input.
+///
+/// The accepted grammar is an optional `+` or `-` sign, digits with an optional decimal
+/// point, and an optional exponent introduced by `e` or `E` with its own optional sign.
+/// At least one digit is required before the exponent, e.g. `1`, `1.`, `.5` and `-1_000.5e-3`.
+/// A `_` separator is only allowed between two digits of the integer or fractional part, not
+/// in the exponent. Whitespace is not accepted.
+///
+/// The value may not exceed `max` and the resulting scale may not exceed `max_scale`,
+/// returning [ErrorCode::ExceedsRange] and [ErrorCode::ExceedsPrecisionRange] respectively.
+/// A positive exponent moves digits from the scale into the value, so `1.5e3` parses as 1500
+/// at scale 0, and a negative exponent increases the scale.
+pub(crate) fn parse_decimal<T: UnsignedInt>(
+    bytes: &[u8],
+    max: T,
+    max_scale: u8,
+) -> Result<Parsed<T>, ErrorCode> {
+    if bytes.is_empty() {
+        return Err(ErrorCode::ParseErrorEmpty);
+    }
+
+    let mut position = 0;
+    let negative = match bytes[0] {
+        b'-' => {
+            position += 1;
+            true
+        }
+        b'+' => {
+            position += 1;
+            false
+        }
+        _ => false,
+    };
+
+    let mut value = T::zero();
+    let mut fraction_digits: i64 = 0;
+    let mut any_digits = false;
+
+    // integer part
+    let (integer, end) = digits(bytes, position, true, |digit| {
+        value = accumulate(value, digit, max)?;
+        Ok(())
+    })?;
+    any_digits |= integer;
+    position = end;
+
+    // fractional part
+    if bytes.get(position) == Some(&b'.') {
+        position += 1;
+        let (fraction, end) = digits(bytes, position, true, |digit| {
+            value = accumulate(value, digit, max)?;
+            fraction_digits += 1;
+            Ok(())
+        })?;
+        any_digits |= fraction;
+        position = end;
+    }
+
+    if !any_digits {
+        return match bytes.get(position) {
+            Some(b'e') | Some(b'E') | None => Err(ErrorCode::ParseErrorMissingDigits { position }),
+            Some(_) => Err(ErrorCode::ParseErrorInvalidCharacter { position }),
+        };
+    }
+
+    // exponent part
+    let mut exponent: i64 = 0;
+    if matches!(bytes.get(position), Some(b'e') | Some(b'E')) {
+        position += 1;
+        let exponent_negative = match bytes.get(position) {
+            Some(b'-') => {
+                position += 1;
+                true
+            }
+            Some(b'+') => {
+                position += 1;
+                false
+            }
+            _ => false,
+        };
+
+        let start = position;
+        let (present, end) = digits(bytes, position, false, |digit| {
+            // anything beyond a few hundred is out of range for every scale anyway
+            exponent = exponent * 10 + i64::from(digit);
+            if exponent > 10_000 {
+                Err(ErrorCode::ParseErrorExponent { position: start })
+            } else {
+                Ok(())
+            }
+        })?;
+        if !present {
+            return Err(ErrorCode::ParseErrorMissingDigits { position: end });
+        }
+        position = end;
+
+        if exponent_negative {
+            exponent = -exponent;
+        }
+    }
+
+    if position < bytes.len() {
+        return Err(ErrorCode::ParseErrorInvalidCharacter { position });
+    }
+
+    let scale = fraction_digits - exponent;
+    if scale < 0 && !value.is_zero() {
+        // move the exponent into the value
+        value = u32::try_from(scale.unsigned_abs())
+            .ok()
+            .and_then(T::checked_pow10)
+            .and_then(|factor| value.checked_mul(factor))
+            .filter(|value| *value <= max)
+            .ok_or(ErrorCode::ExceedsRange)?;
+    }
+
+    Ok(Parsed {
+        value,
+        scale: u8::try_from(scale.max(0))
+            .ok()
+            .filter(|scale| *scale <= max_scale)
+            .ok_or(ErrorCode::ExceedsPrecisionRange)?,
+        negative,
+    })
+}
+
+/// Consume a run of digits starting at `position`, calling `on_digit` for each one, with `_`
+/// separators only allowed between two digits and only when `separators` is set. Returns
+/// whether any digit was found and the position after the run.
+fn digits(
+    bytes: &[u8],
+    mut position: usize,
+    separators: bool,
+    mut on_digit: impl FnMut(u8) -> Result<(), ErrorCode>,
+) -> Result<(bool, usize), ErrorCode> {
+    let mut any = false;
+
+    while let Some(&byte) = bytes.get(position) {
+        match byte {
+            b'0'..=b'9' => {
+                on_digit(byte - b'0')?;
+                any = true;
+            }
+            b'_' => {
+                let next_is_digit = matches!(bytes.get(position + 1), Some(b'0'..=b'9'));
+                if !separators || !any || !next_is_digit {
+                    return Err(ErrorCode::ParseErrorSeparator { position });
+                }
+            }
+            _ => break,
+        }
+        position += 1;
+    }
+
+    Ok((any, position))
+}
+
+fn accumulate<T: UnsignedInt>(value: T, digit: u8, max: T) -> Result<T, ErrorCode> {
+    value
+        .checked_mul(T::from_small(10))
+        .and_then(|value| value.checked_add(T::from_small(digit.into())))
+        .filter(|value| *value <= max)
+        .ok_or(ErrorCode::ExceedsRange)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::parse::{parse_decimal, Parsed};
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+
+    fn parse(s: &str) -> Result<Parsed<U192>, ErrorCode> {
+        parse_decimal(s.as_bytes(), U192::from(u128::MAX), 38)
+    }
+
+    fn parsed(value: u128, scale: u8, negative: bool) -> Result<Parsed<U192>, ErrorCode> {
+        Ok(Parsed {
+            value: U192::from(value),
+            scale,
+            negative,
+        })
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse("42"), parsed(42, 0, false));
+        assert_eq!(parse("+42"), parsed(42, 0, false));
+        assert_eq!(parse("-42.50"), parsed(4250, 2, true));
+        assert_eq!(parse("1."), parsed(1, 0, false));
+        assert_eq!(parse(".5"), parsed(5, 1, false));
+        assert_eq!(
+            parse("1_000_000.000_001"),
+            parsed(1_000_000_000_001, 6, false)
+        );
+        assert_eq!(parse("1.5e3"), parsed(1500, 0, false));
+        assert_eq!(parse("1.2345e2"), parsed(12345, 2, false));
+        assert_eq!(parse("-1.5E-3"), parsed(15, 4, true));
+        assert_eq!(parse("1e+2"), parsed(100, 0, false));
+        assert_eq!(parse("0e999"), parsed(0, 0, false));
+        assert_eq!(
+            parse("340282366920938463463374607431768211455"),
+            parsed(u128::MAX, 0, false)
+        );
+        assert_eq!(
+            parse("0.00000000000000000000000000000000000001"),
+            parsed(1, 38, false)
+        );
+    }
+
+    #[test]
+    fn test_parse_decimal_errors() {
+        assert_eq!(parse(""), Err(ErrorCode::ParseErrorEmpty));
+        assert_eq!(
+            parse("-"),
+            Err(ErrorCode::ParseErrorMissingDigits { position: 1 })
+        );
+        assert_eq!(
+            parse("."),
+            Err(ErrorCode::ParseErrorMissingDigits { position: 1 })
+        );
+        assert_eq!(
+            parse("1e"),
+            Err(ErrorCode::ParseErrorMissingDigits { position: 2 })
+        );
+        assert_eq!(
+            parse("1e-"),
+            Err(ErrorCode::ParseErrorMissingDigits { position: 3 })
+        );
+        assert_eq!(
+            parse("1.2x"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 3 })
+        );
+        assert_eq!(
+            parse("1-2"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 1 })
+        );
+        assert_eq!(
+            parse("1.2.3"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 3 })
+        );
+        assert_eq!(
+            parse(" 1"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 0 })
+        );
+        assert_eq!(
+            parse("1 "),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 1 })
+        );
+        assert_eq!(
+            parse("_1"),
+            Err(ErrorCode::ParseErrorSeparator { position: 0 })
+        );
+        assert_eq!(
+            parse("1__0"),
+            Err(ErrorCode::ParseErrorSeparator { position: 1 })
+        );
+        assert_eq!(
+            parse("1_.0"),
+            Err(ErrorCode::ParseErrorSeparator { position: 1 })
+        );
+        assert_eq!(
+            parse("1e1_0"),
+            Err(ErrorCode::ParseErrorSeparator { position: 3 })
+        );
+        assert_eq!(
+            parse("1e_1"),
+            Err(ErrorCode::ParseErrorSeparator { position: 2 })
+        );
+        assert_eq!(
+            parse("1e99999"),
+            Err(ErrorCode::ParseErrorExponent { position: 2 })
+        );
+        assert_eq!(
+            parse("340282366920938463463374607431768211456"),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(parse("1e39"), Err(ErrorCode::ExceedsRange));
+        assert_eq!(parse("1e-39"), Err(ErrorCode::ExceedsPrecisionRange));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::decimal::core::UnsignedInt;
+use core::cmp::Ordering;
+
+/// Strategy used to discard digits when a [Decimal](crate::decimal::Decimal) or
+/// [BigDecimal](crate::decimal::BigDecimal) loses precision, e.g. when rescaling to fewer
+/// decimal places or dividing.
+///
+/// Floor and Ceiling follow the sign of the number, whereas AwayFromZero matches the existing
+/// "up" operations (`to_scale_up`, `MulUp`, `DivUp`) which round to the larger magnitude.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards negative infinity: 1.5 -> 1, -1.5 -> -2.
+    Floor,
+    /// Round towards positive infinity: 1.5 -> 2, -1.5 -> -1.
+    Ceiling,
+    /// Truncate the discarded digits: 1.5 -> 1, -1.5 -> -1.
+    #[default]
+    TowardZero,
+    /// Round to the larger magnitude: 1.1 -> 2, -1.1 -> -2.
+    AwayFromZero,
+    /// Round to nearest, ties away from zero: 2.5 -> 3, -2.5 -> -3.
+    HalfUp,
+    /// Round to nearest, ties towards zero: 2.5 -> 2, -2.5 -> -2.
+    HalfDown,
+    /// Round to nearest, ties to the even neighbour (banker's rounding): 2.5 -> 2, 3.5 -> 4.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Divide an unsigned magnitude by a non-zero denominator and round the quotient, where
+    /// `negative` is the sign of the result.
+    pub(crate) fn div<T: UnsignedInt>(self, numerator: T, denominator: T, negative: bool) -> T {
+        self.round(
+            numerator / denominator,
+            numerator % denominator,
+            denominator,
+            negative,
+        )
+    }
+
+    /// Round a truncated quotient given the remainder of its division by a non-zero
+    /// denominator, for divisions carried out in a wider integer.
+    pub(crate) fn round<T: UnsignedInt>(
+        self,
+        quotient: T,
+        remainder: T,
+        denominator: T,
+        negative: bool,
+    ) -> T {
+        if remainder.is_zero() {
+            return quotient;
+        }
+
+        // compare the remainder against the half without doubling it, to avoid overflow
+        let half = remainder.cmp(&(denominator - remainder));
+        let odd = !(quotient % T::from_small(2)).is_zero();
+
+        if self.increment(half, odd, negative) {
+            quotient + T::from_small(1)
+        } else {
+            quotient
+        }
+    }
+
+    /// Divide an unsigned magnitude by 10^exponent and round the quotient, also for powers of
+    /// ten beyond the integer, where the quotient is zero and the numerator is the remainder.
+    pub(crate) fn div_pow10<T: UnsignedInt>(
+        self,
+        numerator: T,
+        exponent: u32,
+        negative: bool,
+    ) -> T {
+        match T::checked_pow10(exponent) {
+            Some(denominator) => self.div(numerator, denominator, negative),
+            None if numerator.is_zero() => numerator,
+            None => {
+                let half = T::checked_pow10(exponent - 1)
+                    .and_then(|tenth| tenth.checked_mul(T::from_small(5)))
+                    .map_or(Ordering::Less, |half| numerator.cmp(&half));
+
+                if self.increment(half, false, negative) {
+                    T::from_small(1)
+                } else {
+                    T::zero()
+                }
+            }
+        }
+    }
+
+    /// Whether to round a truncated quotient to the larger magnitude, given how a non-zero
+    /// remainder compares against half of the denominator.
+    fn increment(self, half: Ordering, odd: bool, negative: bool) -> bool {
+        match self {
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && odd),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::core::RoundingMode;
+
+    #[test]
+    fn test_rounding_mode() {
+        // tenths to integer, for each of
+        // 5.5, 2.5, 1.6, 1.1, 1.0, -1.0, -1.1, -1.6, -2.5, -5.5
+        let values: [(u64, bool); 10] = [
+            (55, false),
+            (25, false),
+            (16, false),
+            (11, false),
+            (10, false),
+            (10, true),
+            (11, true),
+            (16, true),
+            (25, true),
+            (55, true),
+        ];
+        let cases: [(RoundingMode, [u64; 10]); 7] = [
+            (RoundingMode::Floor, [5, 2, 1, 1, 1, 1, 2, 2, 3, 6]),
+            (RoundingMode::Ceiling, [6, 3, 2, 2, 1, 1, 1, 1, 2, 5]),
+            (RoundingMode::TowardZero, [5, 2, 1, 1, 1, 1, 1, 1, 2, 5]),
+            (RoundingMode::AwayFromZero, [6, 3, 2, 2, 1, 1, 2, 2, 3, 6]),
+            (RoundingMode::HalfUp, [6, 3, 2, 1, 1, 1, 1, 2, 3, 6]),
+            (RoundingMode::HalfDown, [5, 2, 2, 1, 1, 1, 1, 2, 2, 5]),
+            (RoundingMode::HalfEven, [6, 2, 2, 1, 1, 1, 1, 2, 2, 6]),
+        ];
+
+        for (mode, expected) in cases {
+            for ((value, negative), expected) in values.iter().zip(expected) {
+                let actual = mode.div(U192::from(*value), U192::from(10), *negative);
+
+                assert_eq!(
+                    actual,
+                    U192::from(expected),
+                    "{:?} of {}{}",
+                    mode,
+                    if *negative { "-" } else { "" },
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rounding_mode_large_denominator() {
+        // test: ties are detected without overflowing on U192::MAX sized remainders
+        let denominator = U192::MAX - U192::from(1);
+        let half = denominator / U192::from(2);
+
+        assert_eq!(
+            RoundingMode::HalfDown.div(half, denominator, false),
+            U192::zero()
+        );
+        assert_eq!(
+            RoundingMode::HalfUp.div(half, denominator, false),
+            U192::from(1)
+        );
+    }
+
+    #[test]
+    fn test_rounding_mode_pow10() {
+        // test: 10^58 does not fit, 6 * 10^57 / 10^58 = 0.6
+        let numerator = U192::from(6) * U192::from(10).pow(U192::from(57));
+
+        assert_eq!(
+            RoundingMode::HalfEven.div_pow10(numerator, 58, false),
+            U192::from(1)
+        );
+        assert_eq!(
+            RoundingMode::TowardZero.div_pow10(numerator, 58, false),
+            U192::zero()
+        );
+        assert_eq!(
+            RoundingMode::HalfUp.div_pow10(numerator, 200, false),
+            U192::zero()
+        );
+        assert_eq!(
+            RoundingMode::Floor.div_pow10(numerator, 200, true),
+            U192::from(1)
+        );
+        assert_eq!(
+            RoundingMode::HalfEven.div_pow10(U192::from(25), 1, false),
+            U192::from(2)
+        );
+    }
+}
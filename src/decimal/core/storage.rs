@@ -0,0 +1,265 @@
+use crate::decimal::core::uint::{U1024, U192, U256, U512};
+use core::fmt::Debug;
+use core::hash::Hash;
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Unsigned integer arithmetic shared by the backing integers of a
+/// [GenericDecimal](crate::decimal::GenericDecimal) and the wider integers their products and
+/// dividends are computed in.
+pub trait UnsignedInt:
+    Copy
+    + Default
+    + Debug
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// Largest representable value.
+    const MAX: Self;
+
+    fn zero() -> Self;
+
+    fn from_small(value: u64) -> Self;
+
+    /// Returns `None` if the value does not fit.
+    fn checked_from_u128(value: u128) -> Option<Self>;
+
+    /// The low 128 bits of the value, discarding any higher bits.
+    fn low_u128(self) -> u128;
+
+    fn is_zero(&self) -> bool;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    /// Returns 10^exponent, or `None` if it does not fit.
+    fn checked_pow10(exponent: u32) -> Option<Self>;
+
+    /// Number of significant bits, zero for a zero value.
+    fn bits(self) -> u32;
+
+    fn shift_left(self, shift: u32) -> Self;
+
+    fn shift_right(self, shift: u32) -> Self;
+}
+
+/// A backing integer of a [GenericDecimal](crate::decimal::GenericDecimal), u64, u128, U192,
+/// U256 or U512. Adding a width is a matter of implementing this trait.
+pub trait DecimalStorage: UnsignedInt {
+    /// An integer twice as wide, which holds the product of any two values, so only the result
+    /// of a multiplication or division has to fit back.
+    type Wide: UnsignedInt + From<Self> + TryInto<Self>;
+
+    /// Name of the decimal type backed by this integer, used in panic and debug output.
+    const NAME: &'static str;
+
+    /// Largest scale whose denominator still fits.
+    const MAX_SCALE: u8;
+}
+
+macro_rules! impl_unsigned_primitive {
+    ($uint:ty) => {
+        impl UnsignedInt for $uint {
+            const MAX: Self = <$uint>::MAX;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn from_small(value: u64) -> Self {
+                value.into()
+            }
+
+            fn checked_from_u128(value: u128) -> Option<Self> {
+                value.try_into().ok()
+            }
+
+            fn low_u128(self) -> u128 {
+                self.into()
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$uint>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$uint>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$uint>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$uint>::checked_div(self, rhs)
+            }
+
+            fn checked_pow10(exponent: u32) -> Option<Self> {
+                <$uint>::checked_pow(10, exponent)
+            }
+
+            fn bits(self) -> u32 {
+                <$uint>::BITS - self.leading_zeros()
+            }
+
+            fn shift_left(self, shift: u32) -> Self {
+                self << shift
+            }
+
+            fn shift_right(self, shift: u32) -> Self {
+                self >> shift
+            }
+        }
+    };
+}
+
+macro_rules! impl_unsigned_uint {
+    ($uint:ident) => {
+        impl UnsignedInt for $uint {
+            const MAX: Self = $uint::MAX;
+
+            fn zero() -> Self {
+                $uint::zero()
+            }
+
+            fn from_small(value: u64) -> Self {
+                $uint::from(value)
+            }
+
+            fn checked_from_u128(value: u128) -> Option<Self> {
+                Some($uint::from(value))
+            }
+
+            fn low_u128(self) -> u128 {
+                $uint::low_u128(&self)
+            }
+
+            fn is_zero(&self) -> bool {
+                $uint::is_zero(self)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                $uint::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                $uint::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                $uint::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                $uint::checked_div(self, rhs)
+            }
+
+            fn checked_pow10(exponent: u32) -> Option<Self> {
+                $uint::from(10).checked_pow($uint::from(exponent))
+            }
+
+            fn bits(self) -> u32 {
+                $uint::bits(&self) as u32
+            }
+
+            fn shift_left(self, shift: u32) -> Self {
+                self << shift as usize
+            }
+
+            fn shift_right(self, shift: u32) -> Self {
+                self >> shift as usize
+            }
+        }
+    };
+}
+
+impl_unsigned_primitive!(u64);
+impl_unsigned_primitive!(u128);
+impl_unsigned_uint!(U192);
+impl_unsigned_uint!(U256);
+impl_unsigned_uint!(U512);
+impl_unsigned_uint!(U1024);
+
+impl DecimalStorage for u64 {
+    type Wide = u128;
+    const NAME: &'static str = "Decimal64";
+    const MAX_SCALE: u8 = 19;
+}
+
+impl DecimalStorage for u128 {
+    type Wide = U256;
+    const NAME: &'static str = "Decimal";
+    const MAX_SCALE: u8 = 38;
+}
+
+impl DecimalStorage for U192 {
+    type Wide = U512;
+    const NAME: &'static str = "BigDecimal";
+    const MAX_SCALE: u8 = 57;
+}
+
+impl DecimalStorage for U256 {
+    type Wide = U512;
+    const NAME: &'static str = "Decimal256";
+    const MAX_SCALE: u8 = 77;
+}
+
+impl DecimalStorage for U512 {
+    type Wide = U1024;
+    const NAME: &'static str = "Decimal512";
+    const MAX_SCALE: u8 = 154;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::storage::{DecimalStorage, UnsignedInt};
+    use crate::decimal::core::uint::{U192, U256, U512};
+
+    fn max_scale_fits<T: DecimalStorage>() -> bool {
+        T::checked_pow10(T::MAX_SCALE.into()).is_some()
+            && T::checked_pow10(u32::from(T::MAX_SCALE) + 1).is_none()
+    }
+
+    fn wide_holds_max_product<T: DecimalStorage>() -> bool {
+        T::Wide::from(T::MAX)
+            .checked_mul(T::Wide::from(T::MAX))
+            .is_some()
+    }
+
+    #[test]
+    fn test_storage() {
+        // test: MAX_SCALE is the largest power of ten that fits
+        assert!(max_scale_fits::<u64>());
+        assert!(max_scale_fits::<u128>());
+        assert!(max_scale_fits::<U192>());
+        assert!(max_scale_fits::<U256>());
+        assert!(max_scale_fits::<U512>());
+
+        // test: the wide integer holds the largest product
+        assert!(wide_holds_max_product::<u64>());
+        assert!(wide_holds_max_product::<u128>());
+        assert!(wide_holds_max_product::<U192>());
+        assert!(wide_holds_max_product::<U256>());
+        assert!(wide_holds_max_product::<U512>());
+
+        // test: bits and shifts agree across widths
+        assert_eq!(UnsignedInt::bits(0u128), 0);
+        assert_eq!(UnsignedInt::bits(u64::MAX), 64);
+        assert_eq!(UnsignedInt::bits(U256::MAX), 256);
+        assert_eq!(U192::from(1).shift_left(100).bits(), 101);
+        assert_eq!(U512::MAX.shift_right(412).low_u128(), (1u128 << 100) - 1);
+    }
+}
@@ -1,5 +1,56 @@
+#![allow(clippy::assign_op_pattern, clippy::manual_div_ceil)]
+
 use uint::construct_uint;
 
 construct_uint! {
     pub struct U192(3);
 }
+
+construct_uint! {
+    pub struct U256(4);
+}
+
+construct_uint! {
+    pub struct U512(8);
+}
+
+construct_uint! {
+    /// Intermediate for [U512] products and dividends, like U512 is for U192 and U256.
+    pub struct U1024(16);
+}
+
+/// Lossless widening of a narrow integer into a wider one, and the checked narrowing back.
+macro_rules! impl_widening {
+    ($narrow:ident, $wide:ident) => {
+        impl From<$narrow> for $wide {
+            fn from(value: $narrow) -> $wide {
+                let mut limbs = [0u64; core::mem::size_of::<$wide>() / 8];
+                limbs[..value.0.len()].copy_from_slice(&value.0);
+                $wide(limbs)
+            }
+        }
+
+        impl TryFrom<$wide> for $narrow {
+            type Error = &'static str;
+
+            fn try_from(value: $wide) -> Result<$narrow, &'static str> {
+                let mut limbs = [0u64; core::mem::size_of::<$narrow>() / 8];
+                let (low, high) = value.0.split_at(limbs.len());
+                if high.iter().any(|&limb| limb != 0) {
+                    return Err(concat!(
+                        "integer overflow when casting to ",
+                        stringify!($narrow)
+                    ));
+                }
+                limbs.copy_from_slice(low);
+                Ok($narrow(limbs))
+            }
+        }
+    };
+}
+
+impl_widening!(U192, U256);
+impl_widening!(U192, U512);
+impl_widening!(U256, U512);
+impl_widening!(U256, U1024);
+impl_widening!(U512, U1024);
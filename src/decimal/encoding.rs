@@ -0,0 +1,519 @@
+//! Stable binary encodings of [Decimal] and [BigDecimal], e.g. for account data.
+//!
+//! Every encoding starts with a version tag, so the layout can evolve without breaking
+//! stored values. All multi-byte integers are little-endian.
+//!
+//! Fixed [Decimal] form, [Decimal::ENCODED_LEN] = 18 bytes:
+//!
+//! | offset | size | field                                          |
+//! |--------|------|------------------------------------------------|
+//! | 0      | 1    | version tag [DECIMAL_V1]                       |
+//! | 1      | 16   | value as u128                                  |
+//! | 17     | 1    | scale in bits 0..6, negative flag in bit 7     |
+//!
+//! Fixed [BigDecimal] form, [BigDecimal::ENCODED_LEN] = 26 bytes:
+//!
+//! | offset | size | field                                          |
+//! |--------|------|------------------------------------------------|
+//! | 0      | 1    | version tag [BIG_DECIMAL_V1]                   |
+//! | 1      | 24   | value as U192, least significant limb first    |
+//! | 25     | 1    | scale in bits 0..6, negative flag in bit 7     |
+//!
+//! Compact varint form, shared by both types, at most [Decimal::MAX_VARINT_LEN] or
+//! [BigDecimal::MAX_VARINT_LEN] bytes:
+//!
+//! | offset | size | field                                          |
+//! |--------|------|------------------------------------------------|
+//! | 0      | 1    | version tag [VARINT_V1]                        |
+//! | 1      | 1    | scale in bits 0..6, negative flag in bit 7     |
+//! | 2      | 1..  | value as unsigned LEB128, without trailing zero bytes |
+//!
+//! Encoding rejects a scale above [MAX_SCALE] for [Decimal] or [BIG_MAX_SCALE] for [BigDecimal]
+//! with [ErrorCode::ExceedsPrecisionRange], as it would overlap the negative flag.
+//!
+//! Decoding rejects unknown version tags, a scale above [MAX_SCALE] for [Decimal] or
+//! [BIG_MAX_SCALE] for [BigDecimal], negative zero, and overlong varints, so every value has
+//! exactly one encoding.
+
+use crate::decimal::core::uint::U192;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::{BigDecimal, Decimal, BIG_MAX_SCALE, MAX_SCALE};
+
+/// Version tag of the fixed 18 byte [Decimal] form
+pub const DECIMAL_V1: u8 = 0x01;
+/// Version tag of the fixed 26 byte [BigDecimal] form
+pub const BIG_DECIMAL_V1: u8 = 0x02;
+/// Version tag of the compact varint form
+pub const VARINT_V1: u8 = 0x03;
+
+const NEGATIVE: u8 = 0x80;
+
+impl Decimal {
+    /// Length of the fixed [Decimal] encoding.
+    pub const ENCODED_LEN: usize = 18;
+    /// Maximum length of the varint [Decimal] encoding, 2 header bytes and 19 value bytes.
+    pub const MAX_VARINT_LEN: usize = 21;
+
+    /// Encode into the fixed 18 byte form, see [encoding](crate::decimal::encoding).
+    /// Returns [ErrorCode::ExceedsPrecisionRange] for a scale above [MAX_SCALE].
+    pub fn to_bytes(&self) -> Result<[u8; Decimal::ENCODED_LEN], ErrorCode> {
+        let mut bytes = [0u8; Decimal::ENCODED_LEN];
+        bytes[0] = DECIMAL_V1;
+        bytes[1..17].copy_from_slice(&self.value.to_le_bytes());
+        bytes[17] = header(self.scale, self.negative && self.value != 0, MAX_SCALE)?;
+        Ok(bytes)
+    }
+
+    /// Decode the fixed 18 byte form, see [encoding](crate::decimal::encoding).
+    pub fn from_bytes(bytes: &[u8; Decimal::ENCODED_LEN]) -> Result<Decimal, ErrorCode> {
+        if bytes[0] != DECIMAL_V1 {
+            return Err(ErrorCode::UnsupportedVersion { version: bytes[0] });
+        }
+
+        let mut value = [0u8; 16];
+        value.copy_from_slice(&bytes[1..17]);
+        let value = u128::from_le_bytes(value);
+        let (scale, negative) = validate(bytes[17], value == 0, MAX_SCALE)?;
+
+        Ok(Decimal::new(value, scale, negative))
+    }
+
+    /// Encode into the compact varint form, see [encoding](crate::decimal::encoding).
+    /// Returns the number of bytes written, or [ErrorCode::BufferTooSmall] with the number of
+    /// bytes required, in which case the buffer is left untouched. Returns
+    /// [ErrorCode::ExceedsPrecisionRange] for a scale above [MAX_SCALE].
+    pub fn to_varint(&self, buf: &mut [u8]) -> Result<usize, ErrorCode> {
+        write_varint(
+            U192::from(self.value),
+            self.scale,
+            self.negative,
+            MAX_SCALE,
+            buf,
+        )
+    }
+
+    /// Decode the compact varint form from the start of `bytes`, see
+    /// [encoding](crate::decimal::encoding). Returns the value and the number of bytes read.
+    pub fn from_varint(bytes: &[u8]) -> Result<(Decimal, usize), ErrorCode> {
+        let (value, scale, negative, len) = read_varint(bytes, MAX_SCALE)?;
+        let value = value.try_into().map_err(|_| ErrorCode::ExceedsRange)?;
+
+        Ok((Decimal::new(value, scale, negative), len))
+    }
+}
+
+impl BigDecimal {
+    /// Length of the fixed [BigDecimal] encoding.
+    pub const ENCODED_LEN: usize = 26;
+    /// Maximum length of the varint [BigDecimal] encoding, 2 header bytes and 28 value bytes.
+    pub const MAX_VARINT_LEN: usize = 30;
+
+    /// Encode into the fixed 26 byte form, see [encoding](crate::decimal::encoding).
+    /// Returns [ErrorCode::ExceedsPrecisionRange] for a scale above [BIG_MAX_SCALE].
+    pub fn to_bytes(&self) -> Result<[u8; BigDecimal::ENCODED_LEN], ErrorCode> {
+        let mut bytes = [0u8; BigDecimal::ENCODED_LEN];
+        bytes[0] = BIG_DECIMAL_V1;
+        self.value.to_little_endian(&mut bytes[1..25]);
+        bytes[25] = header(
+            self.scale,
+            self.negative && !self.value.is_zero(),
+            BIG_MAX_SCALE,
+        )?;
+        Ok(bytes)
+    }
+
+    /// Decode the fixed 26 byte form, see [encoding](crate::decimal::encoding).
+    pub fn from_bytes(bytes: &[u8; BigDecimal::ENCODED_LEN]) -> Result<BigDecimal, ErrorCode> {
+        if bytes[0] != BIG_DECIMAL_V1 {
+            return Err(ErrorCode::UnsupportedVersion { version: bytes[0] });
+        }
+
+        let value = U192::from_little_endian(&bytes[1..25]);
+        let (scale, negative) = validate(bytes[25], value.is_zero(), BIG_MAX_SCALE)?;
+
+        Ok(BigDecimal::new(value, scale, negative))
+    }
+
+    /// Encode into the compact varint form, see [encoding](crate::decimal::encoding).
+    /// Returns the number of bytes written, or [ErrorCode::BufferTooSmall] with the number of
+    /// bytes required, in which case the buffer is left untouched. Returns
+    /// [ErrorCode::ExceedsPrecisionRange] for a scale above [BIG_MAX_SCALE].
+    pub fn to_varint(&self, buf: &mut [u8]) -> Result<usize, ErrorCode> {
+        write_varint(self.value, self.scale, self.negative, BIG_MAX_SCALE, buf)
+    }
+
+    /// Decode the compact varint form from the start of `bytes`, see
+    /// [encoding](crate::decimal::encoding). Returns the value and the number of bytes read.
+    pub fn from_varint(bytes: &[u8]) -> Result<(BigDecimal, usize), ErrorCode> {
+        let (value, scale, negative, len) = read_varint(bytes, BIG_MAX_SCALE)?;
+
+        Ok((BigDecimal::new(value, scale, negative), len))
+    }
+}
+
+fn header(scale: u8, negative: bool, max_scale: u8) -> Result<u8, ErrorCode> {
+    if scale > max_scale {
+        return Err(ErrorCode::ExceedsPrecisionRange);
+    }
+
+    Ok(scale | if negative { NEGATIVE } else { 0 })
+}
+
+fn validate(header: u8, zero: bool, max_scale: u8) -> Result<(u8, bool), ErrorCode> {
+    let scale = header & !NEGATIVE;
+    let negative = header & NEGATIVE != 0;
+    if scale > max_scale {
+        return Err(ErrorCode::ExceedsPrecisionRange);
+    }
+    if negative && zero {
+        return Err(ErrorCode::NonCanonicalEncoding);
+    }
+
+    Ok((scale, negative))
+}
+
+fn write_varint(
+    value: U192,
+    scale: u8,
+    negative: bool,
+    max_scale: u8,
+    buf: &mut [u8],
+) -> Result<usize, ErrorCode> {
+    let header = header(scale, negative && !value.is_zero(), max_scale)?;
+    let groups = value.bits().max(1).div_ceil(7);
+    let required = 2 + groups;
+    if buf.len() < required {
+        return Err(ErrorCode::BufferTooSmall { required });
+    }
+
+    buf[0] = VARINT_V1;
+    buf[1] = header;
+    let mut rest = value;
+    for byte in buf[2..required].iter_mut() {
+        *byte = (rest.low_u32() & 0x7f) as u8;
+        rest >>= 7;
+        if !rest.is_zero() {
+            *byte |= 0x80;
+        }
+    }
+
+    Ok(required)
+}
+
+fn read_varint(bytes: &[u8], max_scale: u8) -> Result<(U192, u8, bool, usize), ErrorCode> {
+    let (&version, rest) = bytes.split_first().ok_or(ErrorCode::TruncatedEncoding)?;
+    if version != VARINT_V1 {
+        return Err(ErrorCode::UnsupportedVersion { version });
+    }
+    let (&header, rest) = rest.split_first().ok_or(ErrorCode::TruncatedEncoding)?;
+
+    let mut value = U192::zero();
+    let mut shift = 0;
+    for (i, &byte) in rest.iter().enumerate() {
+        let group = U192::from(byte & 0x7f);
+        // bits shifted out of the U192 would be lost
+        if shift >= 192 || (shift > 192 - 7 && !(group >> (192 - shift)).is_zero()) {
+            return Err(ErrorCode::ExceedsRange);
+        }
+        value |= group << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            // a trailing zero group could be dropped, so it is not the shortest encoding
+            if byte == 0 && i > 0 {
+                return Err(ErrorCode::NonCanonicalEncoding);
+            }
+            let (scale, negative) = validate(header, value.is_zero(), max_scale)?;
+            return Ok((value, scale, negative, i + 3));
+        }
+    }
+
+    Err(ErrorCode::TruncatedEncoding)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::encoding::{BIG_DECIMAL_V1, DECIMAL_V1, VARINT_V1};
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_fixed_layout() {
+        {
+            let bytes = Decimal::new(0x0102, 6, true).to_bytes().unwrap();
+            let mut expected = [0u8; 18];
+            expected[0] = DECIMAL_V1;
+            expected[1] = 0x02;
+            expected[2] = 0x01;
+            expected[17] = 0x80 | 6;
+            assert_eq!(bytes, expected);
+        }
+
+        {
+            let bytes = BigDecimal::new(U192::MAX, 57, false).to_bytes().unwrap();
+            assert_eq!(bytes[0], BIG_DECIMAL_V1);
+            assert_eq!(bytes[1..25], [0xff; 24]);
+            assert_eq!(bytes[25], 57);
+        }
+
+        {
+            // test: negative zero is encoded without the sign
+            let bytes = Decimal::new(0, 2, true).to_bytes().unwrap();
+            assert_eq!(bytes[17], 2);
+            let decoded = Decimal::from_bytes(&bytes).unwrap();
+            assert!(!decoded.negative);
+        }
+    }
+
+    #[test]
+    fn test_fixed_validation() {
+        let mut bytes = Decimal::new(42, 2, false).to_bytes().unwrap();
+
+        {
+            // error: unknown version
+            let mut bytes = bytes;
+            bytes[0] = 0x7f;
+            assert_eq!(
+                Decimal::from_bytes(&bytes),
+                Err(ErrorCode::UnsupportedVersion { version: 0x7f })
+            );
+        }
+
+        {
+            // error: scale above 38
+            let mut bytes = bytes;
+            bytes[17] = 39;
+            assert_eq!(
+                Decimal::from_bytes(&bytes),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+        }
+
+        {
+            // error: negative zero
+            bytes[1] = 0;
+            bytes[17] |= 0x80;
+            assert_eq!(
+                Decimal::from_bytes(&bytes),
+                Err(ErrorCode::NonCanonicalEncoding)
+            );
+        }
+
+        {
+            // error: scale above 57 for a big decimal
+            let mut bytes = BigDecimal::new(U192::one(), 0, false).to_bytes().unwrap();
+            bytes[25] = 58;
+            assert_eq!(
+                BigDecimal::from_bytes(&bytes),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_scale_out_of_range() {
+        let mut buf = [0u8; 32];
+
+        {
+            // error: a scale of 130 would overlap the negative flag, decoding as -5 at scale 2
+            let decimal = Decimal::new(5, 130, false);
+            assert_eq!(decimal.to_bytes(), Err(ErrorCode::ExceedsPrecisionRange));
+            assert_eq!(
+                decimal.to_varint(&mut buf),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+        }
+
+        {
+            // error: scale above 38, but fine for a big decimal
+            let decimal = Decimal::new(5, 39, false);
+            assert_eq!(decimal.to_bytes(), Err(ErrorCode::ExceedsPrecisionRange));
+            let big = BigDecimal::from(decimal);
+            assert_eq!(BigDecimal::from_bytes(&big.to_bytes().unwrap()), Ok(big));
+        }
+
+        {
+            // error: scale above 57 for a big decimal
+            let decimal = BigDecimal::new(U192::one(), 58, true);
+            assert_eq!(decimal.to_bytes(), Err(ErrorCode::ExceedsPrecisionRange));
+            assert_eq!(
+                decimal.to_varint(&mut buf),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_varint() {
+        let mut buf = [0u8; 32];
+
+        {
+            let len = Decimal::new(300, 2, true).to_varint(&mut buf).unwrap();
+            assert_eq!(&buf[..len], &[VARINT_V1, 0x82, 0xac, 0x02]);
+            assert_eq!(
+                Decimal::from_varint(&buf[..len]),
+                Ok((Decimal::new(300, 2, true), 4))
+            );
+        }
+
+        {
+            let len = Decimal::new(0, 0, false).to_varint(&mut buf).unwrap();
+            assert_eq!(&buf[..len], &[VARINT_V1, 0, 0]);
+        }
+
+        {
+            let len = Decimal::new(u128::MAX, 38, false)
+                .to_varint(&mut buf)
+                .unwrap();
+            assert_eq!(len, Decimal::MAX_VARINT_LEN);
+            let len = BigDecimal::new(U192::MAX, 57, false)
+                .to_varint(&mut buf)
+                .unwrap();
+            assert_eq!(len, BigDecimal::MAX_VARINT_LEN);
+        }
+
+        {
+            // test: trailing bytes are left for the caller
+            let bytes = [VARINT_V1, 0, 0x05, 0xff, 0xff];
+            assert_eq!(
+                Decimal::from_varint(&bytes),
+                Ok((Decimal::new(5, 0, false), 3))
+            );
+        }
+
+        {
+            // error: buffer too small
+            let mut small = [0u8; 3];
+            assert_eq!(
+                Decimal::new(300, 2, true).to_varint(&mut small),
+                Err(ErrorCode::BufferTooSmall { required: 4 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_varint_validation() {
+        // error: unknown version
+        assert_eq!(
+            Decimal::from_varint(&[DECIMAL_V1, 0, 0]),
+            Err(ErrorCode::UnsupportedVersion {
+                version: DECIMAL_V1
+            })
+        );
+
+        // error: missing header, missing value and unterminated value
+        assert_eq!(
+            Decimal::from_varint(&[VARINT_V1]),
+            Err(ErrorCode::TruncatedEncoding)
+        );
+        assert_eq!(
+            Decimal::from_varint(&[VARINT_V1, 0]),
+            Err(ErrorCode::TruncatedEncoding)
+        );
+        assert_eq!(
+            Decimal::from_varint(&[VARINT_V1, 0, 0x80]),
+            Err(ErrorCode::TruncatedEncoding)
+        );
+
+        // error: overlong encoding of 1
+        assert_eq!(
+            Decimal::from_varint(&[VARINT_V1, 0, 0x81, 0x00]),
+            Err(ErrorCode::NonCanonicalEncoding)
+        );
+
+        // error: negative zero
+        assert_eq!(
+            Decimal::from_varint(&[VARINT_V1, 0x80, 0]),
+            Err(ErrorCode::NonCanonicalEncoding)
+        );
+
+        // error: scale above 38, but fine for a big decimal
+        assert_eq!(
+            Decimal::from_varint(&[VARINT_V1, 39, 1]),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+        assert!(BigDecimal::from_varint(&[VARINT_V1, 39, 1]).is_ok());
+
+        // error: value above u128::MAX, but fine for a big decimal
+        let mut buf = [0u8; 32];
+        let big = BigDecimal::new(U192::from(u128::MAX) + 1, 0, false);
+        let len = big.to_varint(&mut buf).unwrap();
+        assert_eq!(
+            Decimal::from_varint(&buf[..len]),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(BigDecimal::from_varint(&buf[..len]), Ok((big, len)));
+
+        // error: value above U192::MAX
+        let mut bytes = [0xffu8; 30];
+        bytes[0] = VARINT_V1;
+        bytes[1] = 0;
+        bytes[29] = 0x7f;
+        assert_eq!(
+            BigDecimal::from_varint(&bytes),
+            Err(ErrorCode::ExceedsRange)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_decimal_round_trip(value: u128, scale in 0..=38u8, negative: bool) {
+            let decimal = Decimal::new(value, scale, negative && value != 0);
+
+            let decoded = Decimal::from_bytes(&decimal.to_bytes().unwrap()).unwrap();
+            prop_assert_eq!((decoded.value, decoded.scale, decoded.negative),
+                (decimal.value, decimal.scale, decimal.negative));
+
+            let mut buf = [0u8; Decimal::MAX_VARINT_LEN];
+            let len = decimal.to_varint(&mut buf).unwrap();
+            let (decoded, read) = Decimal::from_varint(&buf).unwrap();
+            prop_assert_eq!(read, len);
+            prop_assert_eq!((decoded.value, decoded.scale, decoded.negative),
+                (decimal.value, decimal.scale, decimal.negative));
+        }
+
+        #[test]
+        fn test_big_decimal_round_trip(limbs: [u64; 3], scale in 0..=57u8, negative: bool) {
+            let value = U192(limbs);
+            let decimal = BigDecimal::new(value, scale, negative && !value.is_zero());
+
+            let decoded = BigDecimal::from_bytes(&decimal.to_bytes().unwrap()).unwrap();
+            prop_assert_eq!((decoded.value, decoded.scale, decoded.negative),
+                (decimal.value, decimal.scale, decimal.negative));
+
+            let mut buf = [0u8; BigDecimal::MAX_VARINT_LEN];
+            let len = decimal.to_varint(&mut buf).unwrap();
+            let (decoded, read) = BigDecimal::from_varint(&buf).unwrap();
+            prop_assert_eq!(read, len);
+            prop_assert_eq!((decoded.value, decoded.scale, decoded.negative),
+                (decimal.value, decimal.scale, decimal.negative));
+        }
+
+        #[test]
+        fn test_scale_round_trip_or_reject(value in 1..=u128::MAX, scale: u8, negative: bool) {
+            let decimal = Decimal::new(value, scale, negative);
+            let mut buf = [0u8; Decimal::MAX_VARINT_LEN];
+
+            match decimal.to_bytes() {
+                Ok(bytes) => {
+                    prop_assert!(scale <= 38);
+                    let decoded = Decimal::from_bytes(&bytes).unwrap();
+                    prop_assert_eq!((decoded.scale, decoded.negative), (scale, negative));
+                }
+                Err(error) => {
+                    prop_assert!(scale > 38);
+                    prop_assert_eq!(error, ErrorCode::ExceedsPrecisionRange);
+                }
+            }
+            prop_assert_eq!(decimal.to_varint(&mut buf).is_ok(), scale <= 38);
+        }
+
+        #[test]
+        fn test_decode_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..40)) {
+            let _ = Decimal::from_varint(&bytes);
+            let _ = BigDecimal::from_varint(&bytes);
+        }
+    }
+}
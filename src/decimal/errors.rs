@@ -1,20 +1,66 @@
-use thiserror::Error;
+use core::fmt;
 
-/// Error codes related to [Decimal].
-#[derive(Error, Debug)]
+/// Error codes related to [Decimal](crate::decimal::Decimal).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorCode {
-    #[error("Unable to parse input")]
     ParseError,
-    #[error("Unable to parse empty input")]
     ParseErrorEmpty,
-    #[error("Unable to parse non base 10 input")]
     ParseErrorBase10,
-    #[error("Scale is different")]
     DifferentScale,
-    #[error("Exceeds allowable range for value")]
     ExceedsRange,
-    #[error("Exceeds allowable range for precision")]
     ExceedsPrecisionRange,
-    #[error("Signed decimals not supported for this function")]
     SignedDecimalsNotSupported,
+    DivisionByZero,
+    ParseErrorInvalidCharacter { position: usize },
+    ParseErrorMissingDigits { position: usize },
+    ParseErrorSeparator { position: usize },
+    ParseErrorExponent { position: usize },
+    BufferTooSmall { required: usize },
+    UnsupportedVersion { version: u8 },
+    TruncatedEncoding,
+    NonCanonicalEncoding,
+    NotFinite,
 }
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorCode::ParseError => write!(f, "Unable to parse input"),
+            ErrorCode::ParseErrorEmpty => write!(f, "Unable to parse empty input"),
+            ErrorCode::ParseErrorBase10 => write!(f, "Unable to parse non base 10 input"),
+            ErrorCode::DifferentScale => write!(f, "Scale is different"),
+            ErrorCode::ExceedsRange => write!(f, "Exceeds allowable range for value"),
+            ErrorCode::ExceedsPrecisionRange => {
+                write!(f, "Exceeds allowable range for precision")
+            }
+            ErrorCode::SignedDecimalsNotSupported => {
+                write!(f, "Signed decimals not supported for this function")
+            }
+            ErrorCode::DivisionByZero => write!(f, "Division by zero"),
+            ErrorCode::ParseErrorInvalidCharacter { position } => {
+                write!(f, "Invalid character at position {}", position)
+            }
+            ErrorCode::ParseErrorMissingDigits { position } => {
+                write!(f, "Expected digits at position {}", position)
+            }
+            ErrorCode::ParseErrorSeparator { position } => {
+                write!(f, "Misplaced digit separator at position {}", position)
+            }
+            ErrorCode::ParseErrorExponent { position } => {
+                write!(f, "Exponent out of range at position {}", position)
+            }
+            ErrorCode::BufferTooSmall { required } => {
+                write!(f, "Buffer too small, {} bytes required", required)
+            }
+            ErrorCode::UnsupportedVersion { version } => {
+                write!(f, "Unsupported encoding version {}", version)
+            }
+            ErrorCode::TruncatedEncoding => write!(f, "Encoding ends unexpectedly"),
+            ErrorCode::NonCanonicalEncoding => write!(f, "Encoding is not canonical"),
+            ErrorCode::NotFinite => write!(f, "Value is not finite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErrorCode {}
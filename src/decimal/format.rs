@@ -0,0 +1,416 @@
+use crate::decimal::core::{DecimalStorage, RoundingMode, UnsignedInt};
+use crate::decimal::GenericDecimal;
+use core::fmt;
+use core::fmt::Write;
+
+/// Builder for human readable output of every [GenericDecimal] width, e.g. for dashboards and
+/// logs.
+///
+/// ```
+/// use logarithm::decimal::core::RoundingMode;
+/// use logarithm::decimal::format::DecimalFormat;
+/// use logarithm::decimal::Decimal;
+///
+/// let format = DecimalFormat::new()
+///     .precision(2)
+///     .rounding(RoundingMode::HalfUp)
+///     .grouping(',');
+///
+/// let decimal = Decimal::new(1_234_567_125, 3, false);
+/// assert_eq!(format.display(decimal).to_string(), "1,234,567.13");
+/// ```
+///
+/// Plain [Display](fmt::Display) of every [GenericDecimal] width uses the default format, which
+/// honors `{:.N}` by rounding half to even like floats do, as well as width, fill, alignment,
+/// `+` and `0` flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecimalFormat {
+    precision: Option<usize>,
+    rounding: RoundingMode,
+    grouping: Option<char>,
+    decimal_point: char,
+}
+
+impl Default for DecimalFormat {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            rounding: RoundingMode::HalfEven,
+            grouping: None,
+            decimal_point: '.',
+        }
+    }
+}
+
+impl DecimalFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of decimal places to show, rounding or padding with zeros. A precision given in
+    /// the format string, e.g. `{:.2}`, takes priority.
+    pub fn precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Rounding mode used when the precision drops decimal places, [RoundingMode::HalfEven]
+    /// by default.
+    pub fn rounding(self, rounding: RoundingMode) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Separator inserted between each group of three integer digits.
+    pub fn grouping(self, separator: char) -> Self {
+        Self {
+            grouping: Some(separator),
+            ..self
+        }
+    }
+
+    /// Character used as the decimal point, '.' by default.
+    pub fn decimal_point(self, decimal_point: char) -> Self {
+        Self {
+            decimal_point,
+            ..self
+        }
+    }
+
+    /// Wrap a value of any width to be formatted with this format through
+    /// [Display](fmt::Display).
+    pub fn display<T: DecimalStorage>(&self, value: GenericDecimal<T>) -> Formatted<T> {
+        Formatted {
+            format: *self,
+            value,
+        }
+    }
+
+    /// Format a value into an owned string.
+    #[cfg(feature = "alloc")]
+    pub fn format<T: DecimalStorage>(&self, value: GenericDecimal<T>) -> alloc::string::String {
+        use alloc::string::ToString;
+        self.display(value).to_string()
+    }
+
+    fn fmt<T: DecimalStorage>(
+        &self,
+        value: GenericDecimal<T>,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        let precision = f.precision().or(self.precision);
+        let mut value = value;
+        if let Some(precision) = precision {
+            if precision < value.scale as usize {
+                let dropped = u32::from(value.scale) - precision as u32;
+                value = GenericDecimal::new(
+                    self.rounding
+                        .div_pow10(value.value, dropped, value.negative),
+                    precision as u8,
+                    value.negative,
+                );
+            }
+        }
+
+        let mut buf = [0u8; MAX_DIGITS];
+        let digits = to_digits(value.value, &mut buf);
+        let scale = value.scale as usize;
+        let (integer, fraction) = if digits.len() > scale {
+            digits.split_at(digits.len() - scale)
+        } else {
+            ("0", digits)
+        };
+        let places = precision.unwrap_or(scale).max(scale);
+
+        let rep = |w: &mut dyn fmt::Write| -> fmt::Result {
+            for (i, digit) in integer.chars().enumerate() {
+                if let Some(separator) = self.grouping {
+                    if i > 0 && (integer.len() - i) % 3 == 0 {
+                        w.write_char(separator)?;
+                    }
+                }
+                w.write_char(digit)?;
+            }
+
+            if places > 0 {
+                w.write_char(self.decimal_point)?;
+                zeros(w, scale - fraction.len())?;
+                w.write_str(fraction)?;
+                zeros(w, places - scale)?;
+            }
+            Ok(())
+        };
+
+        pad(f, value.negative, &rep)
+    }
+
+    fn fmt_exp<T: DecimalStorage>(
+        &self,
+        value: GenericDecimal<T>,
+        upper: bool,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        let precision = f.precision().or(self.precision);
+        let mut mantissa = value.value;
+        let mut exponent = -(value.scale as i64);
+
+        // drop trailing zeros, unless a precision asks for them
+        let ten = T::from_small(10);
+        while !mantissa.is_zero() && (mantissa % ten).is_zero() {
+            mantissa = mantissa / ten;
+            exponent += 1;
+        }
+
+        let mut rounded = mantissa;
+        let mut buf = [0u8; MAX_DIGITS];
+        let mut len = to_digits(rounded, &mut buf).len();
+        if let Some(precision) = precision {
+            if precision + 1 < len {
+                let dropped = (len - precision - 1) as u32;
+                rounded = self.rounding.div_pow10(mantissa, dropped, value.negative);
+                exponent += i64::from(dropped);
+                len = to_digits(rounded, &mut buf).len();
+
+                // rounding up may carry into an extra digit, e.g. 9.99 to 10.0
+                if len > precision + 1 {
+                    len -= 1;
+                    exponent += 1;
+                }
+            }
+        }
+
+        let digits = &to_digits(rounded, &mut buf)[..len];
+        let (lead, trail) = digits.split_at(1);
+        let exponent = exponent + trail.len() as i64;
+        let places = precision.unwrap_or(trail.len()).max(trail.len());
+
+        let rep = |w: &mut dyn fmt::Write| -> fmt::Result {
+            w.write_str(lead)?;
+            if places > 0 {
+                w.write_char(self.decimal_point)?;
+                w.write_str(trail)?;
+                zeros(w, places - trail.len())?;
+            }
+            w.write_char(if upper { 'E' } else { 'e' })?;
+            write!(w, "{}", if mantissa.is_zero() { 0 } else { exponent })
+        };
+
+        pad(f, value.negative, &rep)
+    }
+}
+
+/// Number of decimal digits of U512::MAX, the widest backing integer
+pub(crate) const MAX_DIGITS: usize = 155;
+
+/// Write the digits of a value right aligned into a stack buffer, most significant first.
+pub(crate) fn to_digits<T: UnsignedInt>(value: T, buf: &mut [u8; MAX_DIGITS]) -> &str {
+    let ten = T::from_small(10);
+    let mut start = MAX_DIGITS;
+    let mut rest = value;
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (rest % ten).low_u128() as u8;
+        rest = rest / ten;
+        if rest.is_zero() {
+            break;
+        }
+    }
+    core::str::from_utf8(&buf[start..]).expect("digits are ascii")
+}
+
+fn zeros(w: &mut dyn fmt::Write, count: usize) -> fmt::Result {
+    (0..count).try_for_each(|_| w.write_char('0'))
+}
+
+/// Counts the characters of a representation, so it can be padded without buffering it.
+struct Width(usize);
+
+impl fmt::Write for Width {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Pads a representation like [fmt::Formatter::pad_integral] does, honoring width, fill,
+/// alignment, `+` and `0` flags, without allocating.
+fn pad(
+    f: &mut fmt::Formatter,
+    negative: bool,
+    rep: &dyn Fn(&mut dyn fmt::Write) -> fmt::Result,
+) -> fmt::Result {
+    let sign = if negative {
+        Some('-')
+    } else if f.sign_plus() {
+        Some('+')
+    } else {
+        None
+    };
+
+    let mut width = Width(usize::from(sign.is_some()));
+    rep(&mut width)?;
+    let padding = f.width().unwrap_or(0).saturating_sub(width.0);
+
+    if f.sign_aware_zero_pad() {
+        if let Some(sign) = sign {
+            f.write_char(sign)?;
+        }
+        zeros(f, padding)?;
+        return rep(f);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    if let Some(sign) = sign {
+        f.write_char(sign)?;
+    }
+    rep(f)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
+
+/// A value bound to a [DecimalFormat], created by [DecimalFormat::display].
+#[derive(Clone, Copy, Debug)]
+pub struct Formatted<T: DecimalStorage> {
+    format: DecimalFormat,
+    value: GenericDecimal<T>,
+}
+
+impl<T: DecimalStorage> fmt::Display for Formatted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format.fmt(self.value, f)
+    }
+}
+
+impl<T: DecimalStorage> fmt::LowerExp for Formatted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format.fmt_exp(self.value, false, f)
+    }
+}
+
+impl<T: DecimalStorage> fmt::UpperExp for Formatted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format.fmt_exp(self.value, true, f)
+    }
+}
+
+impl<T: DecimalStorage> fmt::Display for GenericDecimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DecimalFormat::default().fmt(*self, f)
+    }
+}
+
+impl<T: DecimalStorage> fmt::LowerExp for GenericDecimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DecimalFormat::default().fmt_exp(*self, false, f)
+    }
+}
+
+impl<T: DecimalStorage> fmt::UpperExp for GenericDecimal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DecimalFormat::default().fmt_exp(*self, true, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::core::RoundingMode;
+    use crate::decimal::format::DecimalFormat;
+    use crate::decimal::wide::{Decimal256, Decimal512, U256};
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_precision() {
+        let decimal = Decimal::new(42_125, 3, false);
+
+        // test: banker's rounding by default, 42.125 = 42.12
+        assert_eq!(format!("{:.2}", decimal), "42.12");
+        assert_eq!(format!("{:.0}", decimal), "42");
+        assert_eq!(format!("{:.5}", decimal), "42.12500");
+        assert_eq!(format!("{:.2}", Decimal::new(42_135, 3, true)), "-42.14");
+        assert_eq!(format!("{:.1}", Decimal::new(4, 3, true)), "0.0");
+
+        // test: width, alignment, sign and zero padding
+        assert_eq!(format!("{:>8.1}", decimal), "    42.1");
+        assert_eq!(format!("{:<8.1}|", decimal), "42.1    |");
+        assert_eq!(format!("{:+.1}", decimal), "+42.1");
+        assert_eq!(
+            format!("{:08.1}", Decimal::new(42_125, 3, true)),
+            "-00042.1"
+        );
+
+        // test: rounding beyond the digits of a U192
+        let big = BigDecimal::new(U192::MAX, 200, false);
+        assert_eq!(format!("{:.2}", big), "0.00");
+    }
+
+    #[test]
+    fn test_format_builder() {
+        let decimal = Decimal::new(1_234_567_125, 3, true);
+
+        {
+            let format = DecimalFormat::new().grouping(',');
+            assert_eq!(format.display(decimal).to_string(), "-1,234,567.125");
+            assert_eq!(format.format(decimal), "-1,234,567.125");
+        }
+
+        {
+            let format = DecimalFormat::new()
+                .grouping('.')
+                .decimal_point(',')
+                .precision(2)
+                .rounding(RoundingMode::HalfUp);
+            assert_eq!(format.display(decimal).to_string(), "-1.234.567,13");
+        }
+
+        {
+            let format = DecimalFormat::new().grouping('_').precision(1);
+            assert_eq!(
+                format.display(Decimal::new(999, 0, false)).to_string(),
+                "999.0"
+            );
+            assert_eq!(
+                format.display(Decimal::new(1000, 0, false)).to_string(),
+                "1_000.0"
+            );
+
+            // test: a format string precision takes priority
+            assert_eq!(format!("{:.3}", format.display(decimal)), "-1_234_567.125");
+        }
+
+        {
+            // test: the wide decimals share the format
+            let format = DecimalFormat::new().grouping(',').precision(2);
+            let wide = Decimal256::new(U256::from(1_234_567_125u128), 3, false);
+            assert_eq!(format.display(wide).to_string(), "1,234,567.12");
+            assert_eq!(format.format(Decimal512::from(wide)), "1,234,567.12");
+        }
+    }
+
+    #[test]
+    fn test_scientific() {
+        assert_eq!(format!("{:e}", Decimal::new(1500, 0, false)), "1.5e3");
+        assert_eq!(format!("{:E}", Decimal::new(1500, 0, false)), "1.5E3");
+        assert_eq!(format!("{:e}", Decimal::new(15, 4, true)), "-1.5e-3");
+        assert_eq!(format!("{:e}", Decimal::new(7, 0, false)), "7e0");
+        assert_eq!(format!("{:e}", Decimal::new(0, 6, false)), "0e0");
+        assert_eq!(format!("{:.2e}", Decimal::new(123456, 3, false)), "1.23e2");
+        assert_eq!(format!("{:.3e}", Decimal::new(15, 1, false)), "1.500e0");
+
+        // test: rounding carries into the exponent, 9.996 = 1.00e1
+        assert_eq!(format!("{:.2e}", Decimal::new(9996, 3, false)), "1.00e1");
+
+        // test: banker's rounding of the mantissa, 2.5 = 2e0
+        assert_eq!(format!("{:.0e}", Decimal::new(25, 1, false)), "2e0");
+
+        let big = BigDecimal::new(U192::from(2_718281828459045235u128), 18, false);
+        assert_eq!(format!("{:.4e}", big), "2.7183e0");
+        assert_eq!(format!("{:>10.1e}", big), "     2.7e0");
+    }
+}
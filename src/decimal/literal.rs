@@ -0,0 +1,210 @@
+//! Support for the [dec!](crate::dec) and [big_dec!](crate::big_dec) macros, which parse
+//! literals in const context so malformed or out of range literals fail to compile.
+
+use crate::decimal::core::uint::U192;
+use crate::decimal::{BigDecimal, Decimal, MAX_SCALE};
+
+/// Create a const [Decimal] from a literal, with the scale inferred from the number of
+/// decimal places. `_` separators are allowed, exponents and suffixes are not.
+///
+/// ```
+/// use logarithm::dec;
+/// use logarithm::decimal::Decimal;
+///
+/// const LOG2_E: Decimal = dec!(1.442695040888);
+/// assert_eq!(LOG2_E, Decimal::new(1_442695040888, 12, false));
+///
+/// let quarter = dec!(-0.25);
+/// assert_eq!((quarter.value, quarter.scale, quarter.negative), (25, 2, true));
+/// ```
+///
+/// ```compile_fail
+/// // error: beyond the range of a u128
+/// let decimal = logarithm::dec!(340282366920938463463374607431768211456);
+/// ```
+#[macro_export]
+macro_rules! dec {
+    ($literal:literal) => {{
+        const DECIMAL: $crate::decimal::Decimal =
+            $crate::decimal::literal::decimal(stringify!($literal));
+        DECIMAL
+    }};
+}
+
+/// Create a const [BigDecimal] from a literal, like [dec!](crate::dec) does for [Decimal].
+///
+/// ```
+/// use logarithm::big_dec;
+///
+/// let ln_2 = big_dec!(0.693147180559945309417232121);
+/// assert_eq!(ln_2.scale, 27);
+/// ```
+#[macro_export]
+macro_rules! big_dec {
+    ($literal:literal) => {{
+        const DECIMAL: $crate::decimal::BigDecimal =
+            $crate::decimal::literal::big_decimal(stringify!($literal));
+        DECIMAL
+    }};
+}
+
+#[doc(hidden)]
+pub const fn decimal(literal: &str) -> Decimal {
+    let (limbs, scale, negative) = parse(literal);
+    if limbs[2] != 0 {
+        panic!("dec!: literal exceeds the range of a u128");
+    }
+    if scale > MAX_SCALE {
+        panic!("dec!: literal exceeds the maximum scale of 38");
+    }
+
+    let value = (limbs[1] as u128) << 64 | limbs[0] as u128;
+    Decimal {
+        value,
+        scale,
+        negative: negative && value != 0,
+    }
+}
+
+#[doc(hidden)]
+pub const fn big_decimal(literal: &str) -> BigDecimal {
+    let (limbs, scale, negative) = parse(literal);
+    let zero = limbs[0] == 0 && limbs[1] == 0 && limbs[2] == 0;
+
+    BigDecimal {
+        value: U192(limbs),
+        scale,
+        negative: negative && !zero,
+    }
+}
+
+/// Parse the digits of a literal into the limbs of a U192, along with the scale and sign.
+const fn parse(literal: &str) -> ([u64; 3], u8, bool) {
+    let bytes = literal.as_bytes();
+    let mut limbs = [0u64; 3];
+    let mut digits = 0;
+    let mut scale = 0;
+    let mut point = false;
+
+    // a negative literal is stringified as a `-` token followed by the literal
+    let mut i = 0;
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    let negative = i < bytes.len() && bytes[i] == b'-';
+    if negative {
+        i += 1;
+    }
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'_' => {}
+            b'.' if !point => point = true,
+            b'.' => panic!("dec!: literal has more than one decimal point"),
+            digit @ b'0'..=b'9' => {
+                limbs = mul_add(limbs, (digit - b'0') as u128);
+                digits += 1;
+                if point {
+                    if scale == u8::MAX {
+                        panic!("dec!: literal exceeds the maximum scale");
+                    }
+                    scale += 1;
+                }
+            }
+            _ => panic!("dec!: literal must be digits with an optional decimal point"),
+        }
+        i += 1;
+    }
+
+    if digits == 0 {
+        panic!("dec!: literal has no digits");
+    }
+    (limbs, scale, negative)
+}
+
+/// limbs * 10 + digit
+const fn mul_add(mut limbs: [u64; 3], digit: u128) -> [u64; 3] {
+    let mut carry = digit;
+    let mut i = 0;
+    while i < limbs.len() {
+        let product = limbs[i] as u128 * 10 + carry;
+        limbs[i] = product as u64;
+        carry = product >> 64;
+        i += 1;
+    }
+
+    if carry != 0 {
+        panic!("dec!: literal exceeds the range of a U192");
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::literal::{big_decimal, decimal};
+    use crate::decimal::{BigDecimal, Decimal};
+
+    const HALF: Decimal = dec!(0.5);
+
+    #[test]
+    fn test_dec() {
+        assert_eq!((HALF.value, HALF.scale, HALF.negative), (5, 1, false));
+
+        let decimal = dec!(-1_000.250);
+        assert_eq!(
+            (decimal.value, decimal.scale, decimal.negative),
+            (1_000_250, 3, true)
+        );
+
+        let decimal = dec!(42);
+        assert_eq!((decimal.value, decimal.scale), (42, 0));
+
+        // test: negative zero is zero
+        let decimal = dec!(-0.00);
+        assert_eq!(
+            (decimal.value, decimal.scale, decimal.negative),
+            (0, 2, false)
+        );
+
+        let decimal = dec!(340282366920938463463374607431768211455);
+        assert_eq!(decimal.value, u128::MAX);
+    }
+
+    #[test]
+    fn test_big_dec() {
+        let decimal = big_dec!(6277101735386680763835789423207666416102355444464034512895);
+        assert_eq!(decimal, BigDecimal::new(U192::MAX, 0, false));
+
+        let decimal = big_dec!(-0.000000000000000000000000000000000000000000000000000000000001);
+        assert_eq!((decimal.value, decimal.scale), (U192::one(), 60));
+        assert!(decimal.negative);
+    }
+
+    #[test]
+    #[should_panic(expected = "dec!: literal exceeds the range of a u128")]
+    fn test_dec_overflow() {
+        decimal("340282366920938463463374607431768211456");
+    }
+
+    #[test]
+    #[should_panic(expected = "dec!: literal exceeds the maximum scale of 38")]
+    fn test_dec_scale() {
+        decimal("0.000000000000000000000000000000000000001");
+    }
+
+    #[test]
+    #[should_panic(expected = "dec!: literal must be digits with an optional decimal point")]
+    fn test_dec_suffix() {
+        decimal("1.5f64");
+    }
+
+    #[test]
+    #[should_panic(expected = "dec!: literal exceeds the range of a U192")]
+    fn test_big_dec_overflow() {
+        big_decimal("6277101735386680763835789423207666416102355444464034512896");
+    }
+}
@@ -1,6 +1,15 @@
+pub(crate) mod ascii;
+pub(crate) mod convert;
+pub(crate) mod numeric;
 pub mod base;
 pub mod core;
 pub mod ops;
 pub mod errors;
+pub mod encoding;
+pub mod format;
+#[doc(hidden)]
+pub mod literal;
+pub mod scaled;
+pub mod wide;
 
 pub use base::*;
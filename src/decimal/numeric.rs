@@ -0,0 +1,414 @@
+//! [num_traits] implementations, so generic numeric code can take [Decimal] and [BigDecimal].
+//!
+//! The operators require matching scales for `+` and `-` and keep the scale of the lhs for
+//! `*` and `/`, so the constructors [Zero], [One], [Bounded] and [FromPrimitive] return values
+//! at [COMPUTE_SCALE] for [Decimal] and [BIG_COMPUTE_SCALE] for [BigDecimal], like
+//! [Decimal::one] does. Conversions to primitives truncate toward zero and return None when
+//! the value does not fit.
+
+use crate::decimal::convert::{scale_float, to_float, to_signed, to_unsigned, FloatParts};
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::RoundingMode;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::{BigDecimal, Decimal, BIG_COMPUTE_SCALE, COMPUTE_SCALE};
+use num_traits::{Bounded, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+
+macro_rules! impl_num_traits {
+    ($t:ident, $scale:expr) => {
+        impl Zero for $t {
+            fn zero() -> $t {
+                $t::zero()
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == $t::zero()
+            }
+        }
+
+        impl One for $t {
+            fn one() -> $t {
+                $t::one()
+            }
+        }
+
+        impl Num for $t {
+            type FromStrRadixErr = ErrorCode;
+
+            /// Parses base 10 only, keeping the scale of the input.
+            fn from_str_radix(s: &str, radix: u32) -> Result<$t, ErrorCode> {
+                if radix != 10 {
+                    return Err(ErrorCode::ParseErrorBase10);
+                }
+                s.parse()
+            }
+        }
+
+        impl Signed for $t {
+            fn abs(&self) -> $t {
+                $t {
+                    negative: false,
+                    ..*self
+                }
+            }
+
+            /// Returns max(self - other, 0) at the larger of the two scales, so the scales
+            /// need not match. Panics if either value does not fit at that scale.
+            fn abs_sub(&self, other: &$t) -> $t {
+                let (lhs, rhs) =
+                    crate::decimal::ops::Align::align(*self, *other).unwrap_or_else(|_| {
+                        panic!("decimal: overflow in method {}::abs_sub()", stringify!($t))
+                    });
+                if lhs <= rhs {
+                    $t::new(Default::default(), lhs.scale, false)
+                } else {
+                    lhs - rhs
+                }
+            }
+
+            fn signum(&self) -> $t {
+                if Zero::is_zero(self) {
+                    $t::zero()
+                } else if self.negative {
+                    -$t::one()
+                } else {
+                    $t::one()
+                }
+            }
+
+            fn is_positive(&self) -> bool {
+                $t::is_positive(*self)
+            }
+
+            fn is_negative(&self) -> bool {
+                $t::is_negative(*self)
+            }
+        }
+
+        impl Bounded for $t {
+            fn min_value() -> $t {
+                $t::min_value($scale)
+            }
+
+            fn max_value() -> $t {
+                $t::max_value($scale)
+            }
+        }
+
+        impl num_traits::CheckedAdd for $t {
+            fn checked_add(&self, v: &$t) -> Option<$t> {
+                crate::decimal::ops::CheckedAdd::checked_add(*self, *v).ok()
+            }
+        }
+
+        impl num_traits::CheckedSub for $t {
+            fn checked_sub(&self, v: &$t) -> Option<$t> {
+                crate::decimal::ops::CheckedSub::checked_sub(*self, *v).ok()
+            }
+        }
+
+        impl num_traits::CheckedMul for $t {
+            fn checked_mul(&self, v: &$t) -> Option<$t> {
+                crate::decimal::ops::CheckedMul::checked_mul(*self, *v).ok()
+            }
+        }
+
+        impl num_traits::CheckedDiv for $t {
+            fn checked_div(&self, v: &$t) -> Option<$t> {
+                crate::decimal::ops::CheckedDiv::checked_div(*self, *v).ok()
+            }
+        }
+
+        impl num_traits::Pow<$t> for $t {
+            type Output = $t;
+
+            /// Raise to a real exponent, see [Pow](crate::decimal::ops::Pow).
+            fn pow(self, rhs: $t) -> $t {
+                crate::decimal::ops::Pow::pow(self, rhs)
+            }
+        }
+
+        impl num_traits::Pow<u32> for $t {
+            type Output = $t;
+
+            /// Raise to an integer exponent by repeated squaring with `*`, see
+            /// [Pow](crate::decimal::ops::Pow). An exponent of 0 returns one at the scale of
+            /// self.
+            fn pow(self, rhs: u32) -> $t {
+                crate::decimal::ops::Pow::pow(self, u128::from(rhs))
+            }
+        }
+    };
+}
+
+impl_num_traits!(Decimal, COMPUTE_SCALE);
+impl_num_traits!(BigDecimal, BIG_COMPUTE_SCALE);
+
+impl ToPrimitive for Decimal {
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(*self).ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(*self).ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        f32::try_from(*self).ok()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
+}
+
+impl FromPrimitive for Decimal {
+    fn from_i64(n: i64) -> Option<Decimal> {
+        Decimal::from(n).checked_to_scale(COMPUTE_SCALE).ok()
+    }
+
+    fn from_i128(n: i128) -> Option<Decimal> {
+        Decimal::from(n).checked_to_scale(COMPUTE_SCALE).ok()
+    }
+
+    fn from_u64(n: u64) -> Option<Decimal> {
+        Decimal::from(n).checked_to_scale(COMPUTE_SCALE).ok()
+    }
+
+    fn from_u128(n: u128) -> Option<Decimal> {
+        Decimal::from(n).checked_to_scale(COMPUTE_SCALE).ok()
+    }
+
+    fn from_f32(n: f32) -> Option<Decimal> {
+        Decimal::from_f32_with(n, COMPUTE_SCALE, RoundingMode::HalfEven).ok()
+    }
+
+    fn from_f64(n: f64) -> Option<Decimal> {
+        Decimal::from_f64_with(n, COMPUTE_SCALE, RoundingMode::HalfEven).ok()
+    }
+}
+
+impl ToPrimitive for BigDecimal {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128()?.try_into().ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        to_signed(self.value, self.scale, self.negative).ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128()?.try_into().ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        to_unsigned(self.value, self.scale, self.negative).ok()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        let float: f32 = to_float(self.value, self.scale, self.negative);
+        float.is_finite().then_some(float)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(to_float(self.value, self.scale, self.negative))
+    }
+}
+
+impl FromPrimitive for BigDecimal {
+    fn from_i64(n: i64) -> Option<BigDecimal> {
+        FromPrimitive::from_i128(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<BigDecimal> {
+        BigDecimal::from(Decimal::from(n))
+            .checked_to_scale(BIG_COMPUTE_SCALE)
+            .ok()
+    }
+
+    fn from_u64(n: u64) -> Option<BigDecimal> {
+        FromPrimitive::from_u128(n.into())
+    }
+
+    fn from_u128(n: u128) -> Option<BigDecimal> {
+        BigDecimal::from(Decimal::from(n))
+            .checked_to_scale(BIG_COMPUTE_SCALE)
+            .ok()
+    }
+
+    fn from_f32(n: f32) -> Option<BigDecimal> {
+        big_from_float(FloatParts::new(n).ok()?)
+    }
+
+    fn from_f64(n: f64) -> Option<BigDecimal> {
+        big_from_float(FloatParts::new(n).ok()?)
+    }
+}
+
+fn big_from_float(parts: FloatParts) -> Option<BigDecimal> {
+    let value: U192 = scale_float(&parts, BIG_COMPUTE_SCALE, RoundingMode::HalfEven).ok()?;
+    let negative = parts.negative && !value.is_zero();
+    Some(BigDecimal::new(value, BIG_COMPUTE_SCALE, negative))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::{BigDecimal, Decimal};
+    use num_traits::{Bounded, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+
+    /// Generic numeric code, as a caller would write it
+    fn sum_of_squares<T: Num + Copy + num_traits::CheckedMul + num_traits::CheckedAdd>(
+        values: &[T],
+    ) -> Option<T> {
+        values
+            .iter()
+            .try_fold(T::zero(), |acc, x| acc.checked_add(&x.checked_mul(x)?))
+    }
+
+    #[test]
+    fn test_generic() {
+        {
+            let values = [
+                Decimal::from_f64(1.5).unwrap(),
+                Decimal::from_i64(-2).unwrap(),
+            ];
+            // 1.5^2 + (-2)^2 = 6.25
+            assert_eq!(sum_of_squares(&values), Some(Decimal::new(625, 2, false)));
+        }
+
+        {
+            let values = [
+                <BigDecimal as FromPrimitive>::from_u64(3).unwrap(),
+                BigDecimal::one(),
+            ];
+            assert_eq!(
+                sum_of_squares(&values),
+                <BigDecimal as FromPrimitive>::from_u64(10)
+            );
+        }
+
+        {
+            // error: overflow
+            let values = [Decimal::max_value(12), Decimal::max_value(12)];
+            assert_eq!(sum_of_squares(&values), None);
+        }
+    }
+
+    #[test]
+    fn test_identities() {
+        assert!(Zero::is_zero(&Decimal::zero()));
+        assert!(Zero::is_zero(&Decimal::new(0, 3, true)));
+        assert!(Decimal::one().is_one());
+        assert!(Decimal::new(1000, 3, false).is_one());
+        assert!(Zero::is_zero(&BigDecimal::zero()));
+        assert!(BigDecimal::one().is_one());
+
+        assert_eq!(
+            Decimal::from_str_radix("-1.50", 10),
+            Ok(Decimal::new(150, 2, true))
+        );
+        assert_eq!(
+            Decimal::from_str_radix("ff", 16),
+            Err(ErrorCode::ParseErrorBase10)
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        let decimal = Decimal::new(1500, 3, true);
+
+        assert_eq!(Signed::abs(&decimal), Decimal::new(1500, 3, false));
+        assert_eq!(decimal.signum(), -Decimal::one());
+        assert_eq!(Decimal::new(0, 3, true).signum(), Decimal::zero());
+        assert!(Signed::is_negative(&decimal));
+        assert!(!Signed::is_positive(&decimal));
+
+        // test: abs_sub is max(a - b, 0)
+        let other = Decimal::new(500, 3, false);
+        assert_eq!(other.abs_sub(&decimal), Decimal::new(2000, 3, false));
+        assert_eq!(decimal.abs_sub(&other), Decimal::new(0, 3, false));
+
+        // test: abs_sub aligns the scales, 2.5 - 0.25 = 2.25
+        let lhs = Decimal::new(25, 1, false);
+        let rhs = Decimal::new(25, 2, false);
+        let actual = lhs.abs_sub(&rhs);
+        assert_eq!((actual.value, actual.scale), (225, 2));
+        let actual = rhs.abs_sub(&lhs);
+        assert_eq!((actual.value, actual.scale, actual.negative), (0, 2, false));
+        let big = BigDecimal::from(lhs).abs_sub(&BigDecimal::from(rhs));
+        assert_eq!((big.value, big.scale), (U192::from(225), 2));
+
+        let big = BigDecimal::new(U192::from(25), 1, true);
+        assert_eq!(Signed::abs(&big), BigDecimal::new(U192::from(25), 1, false));
+        assert_eq!(big.signum(), -BigDecimal::one());
+    }
+
+    #[test]
+    fn test_bounded() {
+        let max: Decimal = Bounded::max_value();
+        assert_eq!((max.value, max.scale, max.negative), (u128::MAX, 12, false));
+        let min: BigDecimal = Bounded::min_value();
+        assert_eq!((min.value, min.scale, min.negative), (U192::MAX, 18, true));
+    }
+
+    #[test]
+    fn test_primitives() {
+        {
+            let decimal = Decimal::new(2_750, 3, true);
+            assert_eq!(decimal.to_i64(), Some(-2));
+            assert_eq!(decimal.to_u64(), None);
+            assert_eq!(decimal.to_f64(), Some(-2.75));
+            assert_eq!(decimal.to_i8(), Some(-2));
+        }
+
+        {
+            let big = BigDecimal::new(U192::MAX, 0, false);
+            assert_eq!(big.to_u128(), None);
+            assert_eq!(big.to_f32(), None);
+            assert_eq!(big.to_f64(), Some(6.277101735386681e57));
+
+            let big = BigDecimal::new(U192::from(2_750), 3, true);
+            assert_eq!(big.to_i128(), Some(-2));
+            assert_eq!(big.to_u32(), None);
+        }
+
+        {
+            // test: constructors use the compute scale
+            let decimal = Decimal::from_i64(-3).unwrap();
+            assert_eq!((decimal.value, decimal.scale), (3_000_000_000_000, 12));
+            let decimal = Decimal::from_f64(0.1).unwrap();
+            assert_eq!((decimal.value, decimal.scale), (100_000_000_000, 12));
+
+            let big = BigDecimal::from_f64(1e39).unwrap();
+            assert_eq!(big.value, U192::from(10).pow(U192::from(57)));
+            assert_eq!(BigDecimal::from_f64(f64::NAN), None);
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        use num_traits::Pow;
+
+        // 1.5^3 = 3.375
+        let decimal = Decimal::new(15, 1, false).to_scale(3);
+        assert_eq!(Pow::pow(decimal, 3u32), Decimal::new(3375, 3, false));
+        // test: x^0 is one at the scale of x
+        let one = Pow::pow(decimal, 0u32);
+        assert_eq!((one.value, one.scale, one.negative), (1000, 3, false));
+        let one = Pow::pow(BigDecimal::from(decimal), 0u32);
+        assert_eq!((one.value, one.scale), (U192::from(1000), 3));
+
+        let big = BigDecimal::new(U192::from(4), 0, false).to_scale(18);
+        let half = BigDecimal::new(U192::from(5), 1, false).to_scale(18);
+        assert_eq!(Pow::pow(big, half), BigDecimal::two());
+    }
+}
@@ -1,29 +1,28 @@
-use crate::decimal::core::uint::U192;
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::GenericDecimal;
 use crate::decimal::errors::ErrorCode;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 pub trait Add<T>: Sized {
     fn add(self, rhs: T) -> Result<Self, ErrorCode>;
 }
 
-/// Add another [Decimal] value to itself, including signed addition.
-impl Add<Decimal> for Decimal {
-    fn add(self, rhs: Decimal) -> Result<Self, ErrorCode> {
+/// Add another [GenericDecimal] value to itself, including signed addition.
+impl<T: DecimalStorage> Add<GenericDecimal<T>> for GenericDecimal<T> {
+    fn add(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
         if self.scale != rhs.scale {
             Err(ErrorCode::DifferentScale)
         } else if self.negative == rhs.negative {
             // covers when both positive, and both negative.
             // just add the add absolute values and use common sign
             // e.g: (-4) + (-3) = -7 ; 4 + 3 = 7;
-            Ok(Self {
-                value: self
-                    .value
+            Ok(Self::new(
+                self.value
                     .checked_add(rhs.value)
-                    .unwrap_or_else(|| panic!("decimal: overflow in method Decimal::add()")),
-                scale: self.scale,
-                negative: self.negative,
-            })
+                    .unwrap_or_else(|| panic!("decimal: overflow in method {}::add()", T::NAME)),
+                self.scale,
+                self.negative,
+            ))
         } else {
             // if different signs value is the difference of absolute values.
             // (so need to know which has bigger absolute value)
@@ -31,81 +30,15 @@ impl Add<Decimal> for Decimal {
             match self.value.cmp(&rhs.value) {
                 Ordering::Greater => {
                     // e.g: 4 + (-3) = 1 ; -4 + 3 = -1;
-                    Ok(Self {
-                        value: self.value.checked_sub(rhs.value).expect("checked_sub"),
-                        scale: self.scale,
-                        negative: self.negative,
-                    })
+                    Ok(Self::new(self.value - rhs.value, self.scale, self.negative))
                 }
                 Ordering::Less => {
                     // e.g: 2 + (-5) = -3 ; -2 + 5 = 3;
-                    Ok(Self {
-                        value: rhs.value.checked_sub(self.value).expect("checked_sub"),
-                        scale: self.scale,
-                        negative: rhs.negative,
-                    })
+                    Ok(Self::new(rhs.value - self.value, self.scale, rhs.negative))
                 }
                 Ordering::Equal => {
                     // if equal abs value and opposite sign then result is zero
-                    Ok(Self {
-                        value: 0,
-                        scale: self.scale,
-                        negative: false,
-                    })
-                }
-            }
-        }
-    }
-}
-
-/// Add another [BigDecimal] value to itself, including signed addition.
-impl Add<BigDecimal> for BigDecimal {
-    fn add(self, rhs: BigDecimal) -> Result<Self, ErrorCode> {
-        if self.scale != rhs.scale {
-            Err(ErrorCode::DifferentScale)
-        } else if self.negative == rhs.negative {
-            // covers when both positive, and both negative.
-            // just add the add absolute values and use common sign
-            // e.g: (-4) + (-3) = -7 ; 4 + 3 = 7;
-            Ok(Self {
-                value: self.value.checked_add(rhs.value).unwrap_or_else(|| {
-                    panic!("decimal: checked_add overflow in method BigDecimal::add()")
-                }),
-                scale: self.scale,
-                negative: self.negative,
-            })
-        } else {
-            // if different signs value is the difference of absolute values.
-            // (so need to know which has bigger absolute value)
-            // sign is the sign of the one with bigger absolute value
-            match self.value.cmp(&rhs.value) {
-                Ordering::Greater => {
-                    // e.g: 4 + (-3) = 1 ; -4 + 3 = -1;
-                    Ok(Self {
-                        value: self.value.checked_sub(rhs.value).unwrap_or_else(|| {
-                            panic!("decimal: checked_sub overflow in method BigDecimal::add()")
-                        }),
-                        scale: self.scale,
-                        negative: self.negative,
-                    })
-                }
-                Ordering::Less => {
-                    // e.g: 2 + (-5) = -3 ; -2 + 5 = 3;
-                    Ok(Self {
-                        value: rhs.value.checked_sub(self.value).unwrap_or_else(|| {
-                            panic!("decimal: checked_sub overflow in method BigDecimal::add()")
-                        }),
-                        scale: self.scale,
-                        negative: rhs.negative,
-                    })
-                }
-                Ordering::Equal => {
-                    // if equal abs value and opposite sign then result is zero
-                    Ok(Self {
-                        value: U192::from(0),
-                        scale: self.scale,
-                        negative: false,
-                    })
+                    Ok(Self::new(T::zero(), self.scale, false))
                 }
             }
         }
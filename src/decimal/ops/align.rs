@@ -0,0 +1,133 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use crate::decimal::GenericDecimal;
+
+/// Opt-in scale alignment for arithmetic between values of different scales, e.g. 6 and 9
+/// decimal token amounts. Both operands are rescaled to the larger scale, or to an explicit
+/// target scale with [Align::align_to], before operating. A rescale that does not fit returns
+/// [ErrorCode::ExceedsRange], and rescaling to a smaller scale truncates.
+pub trait Align<T>: Sized {
+    fn align(self, rhs: T) -> Result<(Self, Self), ErrorCode>;
+    fn align_to(self, rhs: T, scale: u8) -> Result<(Self, Self), ErrorCode>;
+    fn add_aligned(self, rhs: T) -> Result<Self, ErrorCode>;
+    fn sub_aligned(self, rhs: T) -> Result<Self, ErrorCode>;
+    fn mul_aligned(self, rhs: T) -> Result<Self, ErrorCode>;
+    fn div_aligned(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Align two [GenericDecimal] values to a common scale before operating.
+impl<T: DecimalStorage> Align<GenericDecimal<T>> for GenericDecimal<T> {
+    fn align(self, rhs: GenericDecimal<T>) -> Result<(Self, Self), ErrorCode> {
+        self.align_to(rhs, self.scale.max(rhs.scale))
+    }
+
+    fn align_to(self, rhs: GenericDecimal<T>, scale: u8) -> Result<(Self, Self), ErrorCode> {
+        Ok((self.checked_to_scale(scale)?, rhs.checked_to_scale(scale)?))
+    }
+
+    fn add_aligned(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        let (lhs, rhs) = self.align(rhs)?;
+        lhs.checked_add(rhs)
+    }
+
+    fn sub_aligned(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        let (lhs, rhs) = self.align(rhs)?;
+        lhs.checked_sub(rhs)
+    }
+
+    fn mul_aligned(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        let (lhs, rhs) = self.align(rhs)?;
+        lhs.checked_mul(rhs)
+    }
+
+    fn div_aligned(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        let (lhs, rhs) = self.align(rhs)?;
+        lhs.checked_div(rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::Align;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_align() {
+        {
+            // test: 1.500000 and 0.250000000 align to scale 9
+            let a = Decimal::new(1_500000, 6, false);
+            let b = Decimal::new(250000000, 9, false);
+            let (a, b) = a.align(b).unwrap();
+
+            assert_eq!(a, Decimal::new(1_500000000, 9, false));
+            assert_eq!(b, Decimal::new(250000000, 9, false));
+        }
+
+        {
+            // test: 1.500000 and 0.250000000 align to an explicit scale 2
+            let a = Decimal::new(1_500000, 6, false);
+            let b = Decimal::new(250000000, 9, false);
+            let (a, b) = a.align_to(b, 2).unwrap();
+
+            assert_eq!(a, Decimal::new(150, 2, false));
+            assert_eq!(b, Decimal::new(25, 2, false));
+        }
+
+        {
+            // error: u128::MAX at scale 0 cannot be rescaled to scale 1
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(1, 1, false);
+
+            assert!(matches!(a.align(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_aligned_ops() {
+        let a = Decimal::new(1_500000, 6, false);
+        let b = Decimal::new(250000000, 9, true);
+
+        // test: 1.500000 + -0.250000000 = 1.250000000
+        assert_eq!(
+            a.add_aligned(b).unwrap(),
+            Decimal::new(1_250000000, 9, false)
+        );
+        // test: 1.500000 - -0.250000000 = 1.750000000
+        assert_eq!(
+            a.sub_aligned(b).unwrap(),
+            Decimal::new(1_750000000, 9, false)
+        );
+        // test: 1.500000 * -0.250000000 = -0.375000000
+        assert_eq!(a.mul_aligned(b).unwrap(), Decimal::new(375000000, 9, true));
+        // test: 1.500000 / -0.250000000 = -6.000000000
+        assert_eq!(
+            a.div_aligned(b).unwrap(),
+            Decimal::new(6_000000000, 9, true)
+        );
+    }
+
+    #[test]
+    fn test_aligned_ops_big_decimal() {
+        {
+            // test: 1.5 + 0.25 = 1.75
+            let a = BigDecimal::new(U192::from(15), 1, false);
+            let b = BigDecimal::new(U192::from(25), 2, false);
+
+            assert_eq!(
+                a.add_aligned(b).unwrap(),
+                BigDecimal::new(U192::from(175), 2, false)
+            );
+        }
+
+        {
+            // error: U192::MAX at scale 0 cannot be rescaled to scale 1
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::from(1), 1, false);
+
+            assert!(matches!(a.add_aligned(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+}
@@ -1,56 +1,62 @@
-use crate::decimal::core::uint::U192;
-use crate::decimal::Decimal;
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::ops::Div;
+use crate::decimal::GenericDecimal;
 
 pub trait BigDiv<T>: Sized {
     fn big_div(self, rhs: T) -> Self;
 }
 
-/// Divide a [Decimal] over another [Decimal], including signed division after
-/// converting its value to and from a u256 in order to support ranges > u128.
-impl BigDiv<Decimal> for Decimal {
-    fn big_div(self, rhs: Decimal) -> Self {
-        let lhs = U192::try_from(self.value)
-            .unwrap_or_else(|_| panic!("decimal: lhs value does not fit in Decimal::big_div()"));
-        let denominator = U192::try_from(rhs.denominator()).unwrap_or_else(|_| {
-            panic!("decimal: denominator value does not fit in Decimal::big_mul()")
-        });
-        let negative = self.negative != rhs.negative;
-        let rhs = U192::try_from(rhs.value)
-            .unwrap_or_else(|_| panic!("decimal: rhs value does not fit in Decimal::big_div()"));
-
-        let result = lhs
-            .checked_mul(denominator)
-            .unwrap_or_else(|| panic!("decimal: overflow in method Decimal::big_div().checked_mul"))
-            .checked_div(rhs)
-            .unwrap_or_else(|| {
-                panic!("decimal: overflow in method Decimal::big_div().checked_div")
-            });
-
-        let value: u128 = result.try_into().unwrap_or_else(|_| {
-            panic!("decimal: overflow in method Decimal::big_div() casting to u128")
-        });
-
-        Self {
-            value,
-            scale: self.scale,
-            negative,
-        }
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division.
+/// Delegates to [Div], which already widens the dividend to the [DecimalStorage::Wide] integer,
+/// so only the result has to fit.
+impl<T: DecimalStorage> BigDiv<GenericDecimal<T>> for GenericDecimal<T> {
+    fn big_div(self, rhs: GenericDecimal<T>) -> Self {
+        self.div(rhs)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::decimal::core::uint::U192;
     use crate::decimal::ops::BigDiv;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_big_div() {
-        {
+        // shared (lhs, rhs, expected) table for Decimal and BigDecimal
+        let table = [
             // test: 18446744073709551615.000000000 / 0.000000001 = 18446744073709551615000000000.000000000
-            let a = Decimal::new(u128::MAX, 0, false);
-            let b = Decimal::new(1, 0, false);
+            (
+                Decimal::new(u128::MAX, 0, false),
+                Decimal::new(1, 0, false),
+                Decimal::new(u128::MAX, 0, false),
+            ),
+            // test: -1.0 / 3 = -0.3
+            (
+                Decimal::new(10, 1, true),
+                Decimal::new(3, 0, false),
+                Decimal::new(3, 1, true),
+            ),
+        ];
+
+        for (a, b, expected) in table {
+            assert_eq!(a.big_div(b), expected);
+            assert_eq!(
+                BigDecimal::from(a).big_div(BigDecimal::from(b)),
+                BigDecimal::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_big_div_big_decimal() {
+        {
+            // test: U192::MAX (scale 18) / 2 (scale 18) = U192::MAX / 2
+            // where the dividend only fits in the U512 intermediate
+            let a = BigDecimal::new(U192::MAX, 18, false);
+            let b = BigDecimal::new(U192::from(2_000_000_000_000_000_000u128), 18, false);
             let actual = a.big_div(b);
-            let expected = Decimal::new(u128::MAX, 0, false);
+            let expected = BigDecimal::new(U192::MAX / U192::from(2), 18, false);
 
             assert_eq!(actual, expected);
         }
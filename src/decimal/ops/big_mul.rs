@@ -1,58 +1,76 @@
-use crate::decimal::core::uint::U192;
-use crate::decimal::Decimal;
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::ops::Mul;
+use crate::decimal::GenericDecimal;
 
 pub trait BigMul<T>: Sized {
     fn big_mul(self, rhs: T) -> Self;
 }
 
-/// Multiply another [Decimal] value against itself, including signed multiplication after
-/// converting its value to and from a u256 in order to support ranges > u128.
-impl BigMul<Decimal> for Decimal {
-    fn big_mul(self, rhs: Decimal) -> Self {
-        let lhs = U192::try_from(self.value)
-            .unwrap_or_else(|_| panic!("decimal: lhs value does not fit in Decimal::big_mul()"));
-        let denominator = U192::try_from(rhs.denominator()).unwrap_or_else(|_| {
-            panic!("decimal: denominator value does not fit in Decimal::big_mul()")
-        });
-        let negative = self.negative != rhs.negative;
-        let rhs = U192::try_from(rhs.value)
-            .unwrap_or_else(|_| panic!("decimal: rhs value does not fit in Decimal::big_mul()"));
-
-        let result = lhs
-            .checked_mul(rhs)
-            .unwrap_or_else(|| panic!("decimal: overflow in method Decimal::big_mul().checked_mul"))
-            .checked_div(denominator)
-            .unwrap_or_else(|| {
-                panic!("decimal: overflow in method Decimal::big_mul().checked_div")
-            });
-
-        let value: u128 = result.try_into().unwrap_or_else(|_| {
-            panic!("decimal: overflow in method Decimal::big_mul() casting to u128")
-        });
-
-        Self {
-            value,
-            scale: self.scale,
-            negative,
-        }
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication.
+/// Delegates to [Mul], which already widens the product to the [DecimalStorage::Wide] integer,
+/// so only the result has to fit.
+impl<T: DecimalStorage> BigMul<GenericDecimal<T>> for GenericDecimal<T> {
+    fn big_mul(self, rhs: GenericDecimal<T>) -> Self {
+        self.mul(rhs)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::decimal::core::uint::U192;
     use crate::decimal::ops::BigMul;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_big_mul_decimal() {
-        {
+        // shared (lhs, rhs, expected) table for Decimal and BigDecimal
+        let table = [
             // test: 340282366920938463463374607.431768211454 * 0.000000000002 = 680564733841876.926926749214
-            let a = Decimal::new(u128::MAX - 1, 12, false);
-            let b = Decimal::new(2, 12, false);
+            (
+                Decimal::new(u128::MAX - 1, 12, false),
+                Decimal::new(2, 12, false),
+                Decimal::new(680_564_733_841_876_926_926_749_214, 12, false),
+            ),
+            // test: -1.5 * 2 = -3.0
+            (
+                Decimal::new(15, 1, true),
+                Decimal::new(2, 0, false),
+                Decimal::new(30, 1, true),
+            ),
+        ];
+
+        for (a, b, expected) in table {
+            assert_eq!(a.big_mul(b), expected);
+            assert_eq!(
+                BigDecimal::from(a).big_mul(BigDecimal::from(b)),
+                BigDecimal::from(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_big_mul_big_decimal() {
+        {
+            // test: U192::MAX (scale 18) * 0.000000000000000002 = U192::MAX / 500000000000000000
+            // where the product only fits in the U512 intermediate
+            let a = BigDecimal::new(U192::MAX, 18, false);
+            let b = BigDecimal::new(U192::from(2), 18, false);
             let actual = a.big_mul(b);
-            let expected = Decimal::new(680_564_733_841_876_926_926_749_214, 12, false);
+            let expected = BigDecimal::new(
+                U192::MAX / U192::from(500_000_000_000_000_000u64),
+                18,
+                false,
+            );
 
             assert_eq!(actual, expected);
         }
     }
+
+    #[test]
+    #[should_panic(expected = "decimal: overflow in method BigDecimal::mul().try_into")]
+    fn test_big_mul_big_decimal_panic() {
+        let a = BigDecimal::new(U192::MAX, 0, false);
+        let b = BigDecimal::new(U192::from(2), 0, false);
+        a.big_mul(b);
+    }
 }
@@ -0,0 +1,133 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::GenericDecimal;
+use core::cmp::Ordering;
+
+pub trait CheckedAdd<T>: Sized {
+    fn checked_add(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Add another [GenericDecimal] value to itself, including signed addition, returning an error
+/// instead of panicking when the result does not fit.
+impl<T: DecimalStorage> CheckedAdd<GenericDecimal<T>> for GenericDecimal<T> {
+    fn checked_add(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        if self.scale != rhs.scale {
+            Err(ErrorCode::DifferentScale)
+        } else if self.negative == rhs.negative {
+            // covers when both positive, and both negative.
+            // e.g: (-4) + (-3) = -7 ; 4 + 3 = 7;
+            Ok(Self::new(
+                self.value
+                    .checked_add(rhs.value)
+                    .ok_or(ErrorCode::ExceedsRange)?,
+                self.scale,
+                self.negative,
+            ))
+        } else {
+            // if different signs value is the difference of absolute values,
+            // and sign is the sign of the one with bigger absolute value
+            match self.value.cmp(&rhs.value) {
+                // e.g: 4 + (-3) = 1 ; -4 + 3 = -1;
+                Ordering::Greater => {
+                    Ok(Self::new(self.value - rhs.value, self.scale, self.negative))
+                }
+                // e.g: 2 + (-5) = -3 ; -2 + 5 = 3;
+                Ordering::Less => Ok(Self::new(rhs.value - self.value, self.scale, rhs.negative)),
+                // if equal abs value and opposite sign then result is zero
+                Ordering::Equal => Ok(Self::new(T::zero(), self.scale, false)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::CheckedAdd;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_checked_add() {
+        {
+            // error: 0.000012 + 1.3 = DecimalError::DifferentScale
+            let a = Decimal::new(12, 6, false);
+            let b = Decimal::new(13, 2, false);
+            let actual = a.checked_add(b);
+
+            assert!(matches!(actual, Err(ErrorCode::DifferentScale)));
+        }
+
+        {
+            // test: 1.2 + 1.3 = 2.5
+            let a = Decimal::new(12, 1, false);
+            let b = Decimal::new(13, 1, false);
+            let expected = Decimal::new(25, 1, false);
+
+            assert_eq!(a.checked_add(b).unwrap(), expected);
+        }
+
+        {
+            // test: 4 + (-3) = +1
+            let a = Decimal::new(4, 0, false);
+            let b = Decimal::new(3, 0, true);
+            let expected = Decimal::new(1, 0, false);
+
+            assert_eq!(a.checked_add(b).unwrap(), expected);
+        }
+
+        {
+            // test: 2 + (-5) = -3;
+            let a = Decimal::new(2, 0, false);
+            let b = Decimal::new(5, 0, true);
+            let expected = Decimal::new(3, 0, true);
+
+            assert_eq!(a.checked_add(b).unwrap(), expected);
+        }
+
+        {
+            // test: -2 + 2 = 0
+            let a = Decimal::new(2, 0, true);
+            let b = Decimal::new(2, 0, false);
+            let expected = Decimal::new(0, 0, false);
+
+            assert_eq!(a.checked_add(b).unwrap(), expected);
+        }
+
+        {
+            // error: u128::MAX + 1 = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX, 2, false);
+            let b = Decimal::new(1, 2, false);
+
+            assert!(matches!(a.checked_add(b), Err(ErrorCode::ExceedsRange)));
+        }
+
+        {
+            // error: -u128::MAX + (-1) = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX, 2, true);
+            let b = Decimal::new(1, 2, true);
+
+            assert!(matches!(a.checked_add(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_add_big_decimal() {
+        {
+            // test: 2 + (-5) = -3;
+            let a = BigDecimal::new(U192::from(2), 0, false);
+            let b = BigDecimal::new(U192::from(5), 0, true);
+            let expected = BigDecimal::new(U192::from(3), 0, true);
+
+            assert_eq!(a.checked_add(b).unwrap(), expected);
+        }
+
+        {
+            // error: U192::MAX + 1 = ErrorCode::ExceedsRange
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::from(1), 0, false);
+
+            assert!(matches!(a.checked_add(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+}
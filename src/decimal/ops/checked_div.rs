@@ -0,0 +1,154 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::GenericDecimal;
+
+pub trait CheckedDiv<T>: Sized {
+    fn checked_div(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division, returning
+/// an error instead of panicking. The dividend is widened to the [DecimalStorage::Wide]
+/// integer, so only the result has to fit.
+impl<T: DecimalStorage> CheckedDiv<GenericDecimal<T>> for GenericDecimal<T> {
+    fn checked_div(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        if rhs.is_zero() {
+            return Err(ErrorCode::DivisionByZero);
+        }
+
+        let denominator =
+            T::checked_pow10(rhs.scale.into()).ok_or(ErrorCode::ExceedsPrecisionRange)?;
+
+        let dividend = T::Wide::from(self.value) * T::Wide::from(denominator);
+
+        Ok(Self::new(
+            (dividend / T::Wide::from(rhs.value))
+                .try_into()
+                .map_err(|_| ErrorCode::ExceedsRange)?,
+            self.scale,
+            self.negative != rhs.negative,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::CheckedDiv;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_checked_div() {
+        {
+            // test: 0.0000002 / 0.003 = 0.00006666
+            let a = Decimal::new(2, 8, false);
+            let b = Decimal::new(3, 3, false);
+            let expected = Decimal::new(666, 8, false);
+
+            assert_eq!(a.checked_div(b).unwrap(), expected);
+        }
+
+        {
+            // test: -12 / 3 = -4
+            let a = Decimal::new(12, 0, true);
+            let b = Decimal::new(3, 0, false);
+            let expected = Decimal::new(4, 0, true);
+
+            assert_eq!(a.checked_div(b).unwrap(), expected);
+        }
+
+        {
+            // test: 340282366920938463463374607431768211455 / 1.000 without intermediate overflow
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(1_000, 3, false);
+            let expected = Decimal::new(u128::MAX, 0, false);
+
+            assert_eq!(a.checked_div(b).unwrap(), expected);
+        }
+
+        {
+            // error: 0.010 / 0.0 = ErrorCode::DivisionByZero
+            let a = Decimal::new(10, 3, false);
+            let b = Decimal::new(0, 1, false);
+
+            assert!(matches!(a.checked_div(b), Err(ErrorCode::DivisionByZero)));
+        }
+
+        {
+            // error: 340282366920938463463374607431768211455 / 0.5 = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(5, 1, false);
+
+            assert!(matches!(a.checked_div(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_div_big_decimal() {
+        {
+            // test: 340282366920938463426481119284349108225
+            // / 18446744073709551615
+            // = 18446744073709551615
+            let a = BigDecimal::new(
+                U192([1, u64::MAX.checked_sub(1).expect("u64::MAX"), 0]),
+                0,
+                false,
+            );
+            let b = BigDecimal::new(U192::from(u64::MAX), 0, false);
+            let expected = BigDecimal::new(U192::from(u64::MAX), 0, false);
+
+            assert_eq!(a.checked_div(b).unwrap(), expected);
+        }
+
+        {
+            // error: 1 / 0 = ErrorCode::DivisionByZero
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(0), 0, false);
+
+            assert!(matches!(a.checked_div(b), Err(ErrorCode::DivisionByZero)));
+        }
+
+        {
+            // test: 10^39 / 10^19 = 10^20 at 18 decimals, the dividend exceeds a U192 but
+            // the result fits
+            let a = BigDecimal::new(U192::from(10).pow(57.into()), 18, false);
+            let b = BigDecimal::new(U192::from(10).pow(37.into()), 18, false);
+            let expected = BigDecimal::new(U192::from(10).pow(38.into()), 18, false);
+
+            assert_eq!(a.checked_div(b).unwrap(), expected);
+        }
+
+        {
+            // error: U192::MAX / 0.1 = ErrorCode::ExceedsRange
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::from(1), 1, false);
+
+            assert!(matches!(a.checked_div(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_div_scale_out_of_range() {
+        {
+            // error: 1 / 1e-39 = ErrorCode::ExceedsPrecisionRange
+            let a = Decimal::new(1, 0, false);
+            let b = Decimal::new(1, 39, false);
+
+            assert!(matches!(
+                a.checked_div(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+
+        {
+            // error: 1 / 1e-58 = ErrorCode::ExceedsPrecisionRange
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(1), 58, false);
+
+            assert!(matches!(
+                a.checked_div(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+    }
+}
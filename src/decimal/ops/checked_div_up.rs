@@ -0,0 +1,120 @@
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::GenericDecimal;
+
+pub trait CheckedDivUp<T>: Sized {
+    fn checked_div_up(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division, and
+/// round up (ceiling) the value, returning an error instead of panicking. The dividend is
+/// widened to the [DecimalStorage::Wide] integer, so only the result has to fit.
+impl<T: DecimalStorage> CheckedDivUp<GenericDecimal<T>> for GenericDecimal<T> {
+    fn checked_div_up(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        if rhs.is_zero() {
+            return Err(ErrorCode::DivisionByZero);
+        }
+
+        let denominator =
+            T::checked_pow10(rhs.scale.into()).ok_or(ErrorCode::ExceedsPrecisionRange)?;
+
+        let divisor = T::Wide::from(rhs.value);
+        let dividend = T::Wide::from(self.value) * T::Wide::from(denominator);
+
+        Ok(Self::new(
+            ((dividend + divisor - T::Wide::from_small(1)) / divisor)
+                .try_into()
+                .map_err(|_| ErrorCode::ExceedsRange)?,
+            self.scale,
+            self.negative != rhs.negative,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::CheckedDivUp;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_checked_div_up() {
+        {
+            // test: 1 / 2 = 1 rounded up
+            let a = Decimal::new(1, 0, false);
+            let b = Decimal::new(2, 0, false);
+            let expected = Decimal::new(1, 0, false);
+
+            assert_eq!(a.checked_div_up(b).unwrap(), expected);
+        }
+
+        {
+            // test: 0.42 / 10 = 0.042 = 0.05 rounded up
+            let a = Decimal::new(42, 2, false);
+            let b = Decimal::new(10, 0, false);
+            let expected = Decimal::new(5, 2, false);
+
+            assert_eq!(a.checked_div_up(b).unwrap(), expected);
+        }
+
+        {
+            // error: 1 / 0 = ErrorCode::DivisionByZero
+            let a = Decimal::new(1, 0, false);
+            let b = Decimal::new(0, 0, false);
+
+            assert!(matches!(
+                a.checked_div_up(b),
+                Err(ErrorCode::DivisionByZero)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_checked_div_up_big_decimal() {
+        {
+            // test: 2 / 3 = 1 rounded up
+            let a = BigDecimal::new(U192::from(2), 0, false);
+            let b = BigDecimal::new(U192::from(3), 0, false);
+            let expected = BigDecimal::new(U192::from(1), 0, false);
+
+            assert_eq!(a.checked_div_up(b).unwrap(), expected);
+        }
+
+        {
+            // error: 1 / 0 = ErrorCode::DivisionByZero
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(0), 0, false);
+
+            assert!(matches!(
+                a.checked_div_up(b),
+                Err(ErrorCode::DivisionByZero)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_checked_div_up_scale_out_of_range() {
+        {
+            // error: 1 / 1e-39 = ErrorCode::ExceedsPrecisionRange
+            let a = Decimal::new(1, 0, false);
+            let b = Decimal::new(1, 39, false);
+
+            assert!(matches!(
+                a.checked_div_up(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+
+        {
+            // error: 1 / 1e-58 = ErrorCode::ExceedsPrecisionRange
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(1), 58, false);
+
+            assert!(matches!(
+                a.checked_div_up(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+    }
+}
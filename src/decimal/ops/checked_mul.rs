@@ -0,0 +1,189 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::mul::mul_integer;
+use crate::decimal::GenericDecimal;
+
+pub trait CheckedMul<T>: Sized {
+    fn checked_mul(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication,
+/// returning an error instead of panicking when the result does not fit. The product is widened
+/// to the [DecimalStorage::Wide] integer, so only the result has to fit.
+impl<T: DecimalStorage> CheckedMul<GenericDecimal<T>> for GenericDecimal<T> {
+    fn checked_mul(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        let denominator =
+            T::checked_pow10(rhs.scale.into()).ok_or(ErrorCode::ExceedsPrecisionRange)?;
+        let product = T::Wide::from(self.value) * T::Wide::from(rhs.value);
+
+        Ok(Self::new(
+            (product / T::Wide::from(denominator))
+                .try_into()
+                .map_err(|_| ErrorCode::ExceedsRange)?,
+            self.scale,
+            self.negative != rhs.negative,
+        ))
+    }
+}
+
+/// Multiply an unsigned integer value against a [GenericDecimal], returning an error instead of
+/// panicking when the result does not fit.
+impl<T: DecimalStorage> CheckedMul<u128> for GenericDecimal<T> {
+    fn checked_mul(self, rhs: u128) -> Result<Self, ErrorCode> {
+        Ok(Self::new(
+            mul_integer(self.value, rhs).ok_or(ErrorCode::ExceedsRange)?,
+            self.scale,
+            self.negative,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::CheckedMul;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_checked_mul() {
+        {
+            // test: 1.234 * 0.04321 = 0.053
+            let a = Decimal::new(1234, 3, false);
+            let b = Decimal::new(4321, 5, false);
+            let expected = Decimal::new(53, 3, false);
+
+            assert_eq!(a.checked_mul(b).unwrap(), expected);
+        }
+
+        {
+            // test: -4 * 3 = -12
+            let a = Decimal::new(4, 0, true);
+            let b = Decimal::new(3, 0, false);
+            let expected = Decimal::new(12, 0, true);
+
+            assert_eq!(a.checked_mul(b).unwrap(), expected);
+        }
+
+        {
+            // test: 340282366920938463463374607.431768211454 * 0.000000000002 = 680564733841876.926926749214
+            let a = Decimal::new(u128::MAX - 1, 12, false);
+            let b = Decimal::new(2, 12, false);
+            let expected = Decimal::new(680_564_733_841_876_926_926_749_214, 12, false);
+
+            assert_eq!(a.checked_mul(b).unwrap(), expected);
+        }
+
+        {
+            // error: 340282366920938463463374607431768211.454 * 2.000 = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX - 1, 3, false);
+            let b = Decimal::new(2_000, 3, false);
+
+            assert!(matches!(a.checked_mul(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_u128() {
+        // shared (lhs, rhs, expected) table for Decimal and BigDecimal
+        let table = [
+            // test: 98.76 * 555 = 54811.80
+            (
+                Decimal::new(9876, 2, false),
+                555u128,
+                Decimal::new(5481180, 2, false),
+            ),
+            // test: -1.5 * 4 = -6.0
+            (Decimal::new(15, 1, true), 4, Decimal::new(60, 1, true)),
+        ];
+
+        for (a, b, expected) in table {
+            assert_eq!(a.checked_mul(b).unwrap(), expected);
+            assert_eq!(
+                BigDecimal::from(a).checked_mul(b).unwrap(),
+                BigDecimal::from(expected)
+            );
+        }
+
+        {
+            // error: u128::MAX * 2 = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX, 2, false);
+
+            assert!(matches!(a.checked_mul(2u128), Err(ErrorCode::ExceedsRange)));
+        }
+
+        {
+            // test: u128::MAX * 2 fits in a BigDecimal
+            let a = BigDecimal::new(U192::from(u128::MAX), 2, false);
+            let expected = BigDecimal::new(U192::from(u128::MAX) * U192::from(2), 2, false);
+
+            assert_eq!(a.checked_mul(2u128).unwrap(), expected);
+        }
+
+        {
+            // error: U192::MAX * 2 = ErrorCode::ExceedsRange
+            let a = BigDecimal::new(U192::MAX, 2, false);
+
+            assert!(matches!(a.checked_mul(2u128), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_big_decimal() {
+        {
+            // test: 18446744073709551615 * 18446744073709551615
+            // = 340282366920938463426481119284349108225
+            let a = BigDecimal::new(U192::from(u64::MAX), 0, false);
+            let b = BigDecimal::new(U192::from(u64::MAX), 0, false);
+            let expected = BigDecimal::new(
+                U192([1, u64::MAX.checked_sub(1).expect("u64::MAX"), 0]),
+                0,
+                false,
+            );
+
+            assert_eq!(a.checked_mul(b).unwrap(), expected);
+        }
+
+        {
+            // test: 10^19 * 10^19 = 10^38 at 18 decimals, the product exceeds a U192 but
+            // the result fits
+            let a = BigDecimal::new(U192::from(10).pow(37.into()), 18, false);
+            let expected = BigDecimal::new(U192::from(10).pow(56.into()), 18, false);
+
+            assert_eq!(a.checked_mul(a).unwrap(), expected);
+        }
+
+        {
+            // error: U192::MAX * 2 = ErrorCode::ExceedsRange
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::from(2), 0, false);
+
+            assert!(matches!(a.checked_mul(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_scale_out_of_range() {
+        {
+            // error: 1 * 1e-39 = ErrorCode::ExceedsPrecisionRange
+            let a = Decimal::new(1, 0, false);
+            let b = Decimal::new(1, 39, false);
+
+            assert!(matches!(
+                a.checked_mul(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+
+        {
+            // error: 1 * 1e-58 = ErrorCode::ExceedsPrecisionRange
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(1), 58, false);
+
+            assert!(matches!(
+                a.checked_mul(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::GenericDecimal;
+
+pub trait CheckedMulUp<T>: Sized {
+    fn checked_mul_up(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication
+/// and round up (ceiling) the value, returning an error instead of panicking when the result
+/// does not fit. The product is widened to the [DecimalStorage::Wide] integer, so only the
+/// result has to fit.
+impl<T: DecimalStorage> CheckedMulUp<GenericDecimal<T>> for GenericDecimal<T> {
+    fn checked_mul_up(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        let denominator = T::Wide::from(
+            T::checked_pow10(rhs.scale.into()).ok_or(ErrorCode::ExceedsPrecisionRange)?,
+        );
+        let product = T::Wide::from(self.value) * T::Wide::from(rhs.value);
+
+        Ok(Self::new(
+            ((product + denominator - T::Wide::from_small(1)) / denominator)
+                .try_into()
+                .map_err(|_| ErrorCode::ExceedsRange)?,
+            self.scale,
+            self.negative != rhs.negative,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::CheckedMulUp;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_checked_mul_up() {
+        {
+            // test: 0.000000000001 * 0.000000000001 = 0.000000000001 rounded up
+            let a = Decimal::new(1, 12, false);
+            let b = Decimal::new(1, 12, false);
+            let expected = Decimal::new(1, 12, false);
+
+            assert_eq!(a.checked_mul_up(b).unwrap(), expected);
+        }
+
+        {
+            // test: 1.000000 * 0.300000 = 0.300000
+            let a = Decimal::new(1_000_000, 6, false);
+            let b = Decimal::new(300_000, 6, false);
+            let expected = Decimal::new(300_000, 6, false);
+
+            assert_eq!(a.checked_mul_up(b).unwrap(), expected);
+        }
+
+        {
+            // error: 340282366920938463463374607431768211.455 * 2.000 = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX, 3, false);
+            let b = Decimal::new(2_000, 3, false);
+
+            assert!(matches!(a.checked_mul_up(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_up_big_decimal() {
+        {
+            // test: 0.001 * 0.001 = 0.001 rounded up
+            let a = BigDecimal::new(U192::from(1), 3, false);
+            let b = BigDecimal::new(U192::from(1), 3, false);
+            let expected = BigDecimal::new(U192::from(1), 3, false);
+
+            assert_eq!(a.checked_mul_up(b).unwrap(), expected);
+        }
+
+        {
+            // error: U192::MAX * 2 = ErrorCode::ExceedsRange
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::from(2), 0, false);
+
+            assert!(matches!(a.checked_mul_up(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_mul_up_scale_out_of_range() {
+        {
+            // error: 1 * 1e-39 = ErrorCode::ExceedsPrecisionRange
+            let a = Decimal::new(1, 0, false);
+            let b = Decimal::new(1, 39, false);
+
+            assert!(matches!(
+                a.checked_mul_up(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+
+        {
+            // error: 1 * 1e-58 = ErrorCode::ExceedsPrecisionRange
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(1), 58, false);
+
+            assert!(matches!(
+                a.checked_mul_up(b),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            ));
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::CheckedAdd;
+use crate::decimal::GenericDecimal;
+
+pub trait CheckedSub<T>: Sized {
+    fn checked_sub(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Subtract another [GenericDecimal] value from itself, including signed subtraction, returning
+/// an error instead of panicking when the result does not fit.
+impl<T: DecimalStorage> CheckedSub<GenericDecimal<T>> for GenericDecimal<T> {
+    fn checked_sub(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
+        // as a - b is always a + (-b) so we let checked_add handle it
+        let new_rhs = GenericDecimal {
+            negative: !rhs.negative,
+            ..rhs
+        };
+        self.checked_add(new_rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::CheckedSub;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_checked_sub() {
+        {
+            // error: 0.000012 - 1.3 = DecimalError::DifferentScale
+            let a = Decimal::new(12, 6, false);
+            let b = Decimal::new(13, 2, false);
+
+            assert!(matches!(a.checked_sub(b), Err(ErrorCode::DifferentScale)));
+        }
+
+        {
+            // test: 10 - 15 = -5
+            let a = Decimal::new(10, 6, false);
+            let b = Decimal::new(15, 6, false);
+            let expected = Decimal::new(5, 6, true);
+
+            assert_eq!(a.checked_sub(b).unwrap(), expected);
+        }
+
+        {
+            // test: -10 - (-15) = 5
+            let a = Decimal::new(10, 6, true);
+            let b = Decimal::new(15, 6, true);
+            let expected = Decimal::new(5, 6, false);
+
+            assert_eq!(a.checked_sub(b).unwrap(), expected);
+        }
+
+        {
+            // error: -u128::MAX - 1 = ErrorCode::ExceedsRange
+            let a = Decimal::new(u128::MAX, 2, true);
+            let b = Decimal::new(1, 2, false);
+
+            assert!(matches!(a.checked_sub(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_checked_sub_big_decimal() {
+        {
+            // test: 10 - 15 = -5
+            let a = BigDecimal::new(U192::from(10), 6, false);
+            let b = BigDecimal::new(U192::from(15), 6, false);
+            let expected = BigDecimal::new(U192::from(5), 6, true);
+
+            assert_eq!(a.checked_sub(b).unwrap(), expected);
+        }
+
+        {
+            // error: -U192::MAX - 1 = ErrorCode::ExceedsRange
+            let a = BigDecimal::new(U192::MAX, 0, true);
+            let b = BigDecimal::new(U192::from(1), 0, false);
+
+            assert!(matches!(a.checked_sub(b), Err(ErrorCode::ExceedsRange)));
+        }
+    }
+}
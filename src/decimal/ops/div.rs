@@ -1,44 +1,29 @@
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
 
 pub trait Div<T>: Sized {
     fn div(self, rhs: T) -> Self;
 }
 
-/// Divide a [Decimal] over another [Decimal], including signed division.
-impl Div<Decimal> for Decimal {
-    fn div(self, rhs: Decimal) -> Self {
-        Self {
-            value: self
-                .value
-                .checked_mul(rhs.denominator())
-                .unwrap_or_else(|| panic!("decimal: overflow in method Decimal::div().checked_mul"))
-                .checked_div(rhs.value)
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::div().checked_div")
-                }),
-            scale: self.scale,
-            negative: self.negative != rhs.negative,
-        }
-    }
-}
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division. The
+/// dividend is widened to the [DecimalStorage::Wide] integer, so only the result has to fit.
+impl<T: DecimalStorage> Div<GenericDecimal<T>> for GenericDecimal<T> {
+    fn div(self, rhs: GenericDecimal<T>) -> Self {
+        let dividend = T::Wide::from(self.value) * T::Wide::from(rhs.denominator());
 
-/// Divide a [BigDecimal] over another [BigDecimal], including signed division.
-impl Div<BigDecimal> for BigDecimal {
-    fn div(self, rhs: BigDecimal) -> Self {
-        Self {
-            value: self
-                .value
-                .checked_mul(rhs.denominator())
+        Self::new(
+            dividend
+                .checked_div(T::Wide::from(rhs.value))
                 .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method BigDecimal::div().checked_mul")
+                    panic!("decimal: overflow in method {}::div().checked_div", T::NAME)
                 })
-                .checked_div(rhs.value)
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method BigDecimal::div().checked_div")
+                .try_into()
+                .unwrap_or_else(|_| {
+                    panic!("decimal: overflow in method {}::div().try_into", T::NAME)
                 }),
-            scale: self.scale,
-            negative: self.negative != rhs.negative,
-        }
+            self.scale,
+            self.negative != rhs.negative,
+        )
     }
 }
 
@@ -47,6 +32,7 @@ mod test {
     use crate::decimal::core::uint::U192;
     use crate::decimal::ops::Div;
     use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
 
     #[test]
     fn test_div() {
@@ -129,4 +115,22 @@ mod test {
             assert_eq!(actual, expected);
         }
     }
+
+    proptest! {
+        #[test]
+        fn test_div_never_negative_zero(
+            a in 0..1_000_000_000u128, a_negative: bool,
+            b in 1..u128::MAX, b_negative: bool,
+            scale in 0..=9u8,
+        ) {
+            let a = Decimal::new(a, scale, a_negative);
+            let b = Decimal::new(b, scale, b_negative);
+
+            let actual = a.div(b);
+            assert!(!(actual.negative && actual.is_zero()), "{:?}", actual);
+
+            let actual = BigDecimal::from(a).div(BigDecimal::from(b));
+            assert!(!(actual.negative && actual.value.is_zero()), "{:?}", actual);
+        }
+    }
 }
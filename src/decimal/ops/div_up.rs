@@ -1,56 +1,77 @@
-use crate::decimal::Decimal;
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
 
 pub trait DivUp<T>: Sized {
     fn div_up(self, rhs: T) -> Self;
 }
 
-/// Divide a [Decimal] over another [Decimal], including signed division.
-/// and round up (ceiling) the value.
-impl DivUp<Decimal> for Decimal {
-    fn div_up(self, rhs: Decimal) -> Self {
-        Self {
-            value: self
-                .value
-                .checked_mul(rhs.denominator())
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::div_up().checked_mul")
-                })
-                .checked_add(rhs.value.checked_sub(1).unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::div_up().checked_sub")
-                }))
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::div_up().checked_div")
-                })
-                .checked_div(rhs.value)
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::div_up().checked_div")
-                }),
-            scale: self.scale,
-            negative: self.negative != rhs.negative,
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division,
+/// and round up (ceiling) the value. The dividend is widened to the [DecimalStorage::Wide]
+/// integer, so only the result has to fit.
+impl<T: DecimalStorage> DivUp<GenericDecimal<T>> for GenericDecimal<T> {
+    fn div_up(self, rhs: GenericDecimal<T>) -> Self {
+        if rhs.is_zero() {
+            panic!("decimal: division by zero in method {}::div_up()", T::NAME);
         }
+
+        let divisor = T::Wide::from(rhs.value);
+        let dividend = T::Wide::from(self.value) * T::Wide::from(rhs.denominator());
+
+        Self::new(
+            ((dividend + divisor - T::Wide::from_small(1)) / divisor)
+                .try_into()
+                .unwrap_or_else(|_| {
+                    panic!("decimal: overflow in method {}::div_up().try_into", T::NAME)
+                }),
+            self.scale,
+            self.negative != rhs.negative,
+        )
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::decimal::core::uint::U192;
     use crate::decimal::core::Compare;
     use crate::decimal::ops::DivUp;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_div_up() {
-        // 0/n = 0
-        {
-            let a = Decimal::new(0, 0, false);
-            let b = Decimal::new(1, 0, false);
-            assert_eq!(a.div_up(b), Decimal::new(0, 0, false));
-        }
+        // shared (lhs, rhs, expected) table for Decimal and BigDecimal
+        let table = [
+            // 0/n = 0
+            (
+                Decimal::new(0, 0, false),
+                Decimal::new(1, 0, false),
+                Decimal::new(0, 0, false),
+            ),
+            // 1/2 = 1 rounded up
+            (
+                Decimal::new(1, 0, false),
+                Decimal::new(2, 0, false),
+                Decimal::new(1, 0, false),
+            ),
+            // 42.00/10 = 4.20 = 5.00 rounded up
+            (
+                Decimal::new(42, 2, false),
+                Decimal::new(10, 0, false),
+                Decimal::new(5, 2, false),
+            ),
+            // -1/3 = -1 rounded up in magnitude
+            (
+                Decimal::new(1, 0, true),
+                Decimal::new(3, 0, false),
+                Decimal::new(1, 0, true),
+            ),
+        ];
 
-        // 1/2 = 1 rounded up
-        {
-            let a = Decimal::new(1, 0, false);
-            let b = Decimal::new(2, 0, false);
-            assert_eq!(a.div_up(b), Decimal::new(1, 0, false));
+        for (a, b, expected) in table {
+            assert_eq!(a.div_up(b), expected);
+            assert_eq!(
+                BigDecimal::from(a).div_up(BigDecimal::from(b)),
+                BigDecimal::from(expected)
+            );
         }
 
         // 200,000.000001/2 = 100000.000001 rounded up
@@ -61,13 +82,10 @@ mod test {
                 .div_up(b)
                 .lt(Decimal::new(100_000_000_001, 6, false))
                 .unwrap());
-        }
-
-        // 42.00/10 = 4.20 = 5.00 rounded up
-        {
-            let a = Decimal::new(42, 2, false);
-            let b = Decimal::new(10, 0, false);
-            assert_eq!(a.div_up(b), Decimal::new(5, 2, false));
+            assert!(!BigDecimal::from(a)
+                .div_up(BigDecimal::from(b))
+                .lt(BigDecimal::new(U192::from(100_000_000_001u128), 6, false))
+                .unwrap());
         }
     }
 }
@@ -0,0 +1,169 @@
+use crate::decimal::core::{DecimalStorage, RoundingMode};
+use crate::decimal::GenericDecimal;
+
+pub trait DivWith<T>: Sized {
+    fn div_with(self, rhs: T, mode: RoundingMode) -> Self;
+}
+
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division,
+/// and round the value with the given [RoundingMode]. The dividend is widened to the
+/// [DecimalStorage::Wide] integer and rounded there, so only the result has to fit.
+impl<T: DecimalStorage> DivWith<GenericDecimal<T>> for GenericDecimal<T> {
+    fn div_with(self, rhs: GenericDecimal<T>, mode: RoundingMode) -> Self {
+        if rhs.is_zero() {
+            panic!(
+                "decimal: division by zero in method {}::div_with()",
+                T::NAME
+            );
+        }
+
+        let negative = self.negative != rhs.negative;
+        let dividend = T::Wide::from(self.value) * T::Wide::from(rhs.denominator());
+
+        Self::new(
+            mode.div(dividend, T::Wide::from(rhs.value), negative)
+                .try_into()
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "decimal: overflow in method {}::div_with().try_into",
+                        T::NAME
+                    )
+                }),
+            self.scale,
+            negative,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::core::RoundingMode;
+    use crate::decimal::ops::DivWith;
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_div_with() {
+        {
+            // test: 2 / 3 = 0.666666 = 0.67 rounded half even, 0.66 rounded toward zero
+            let a = Decimal::new(200, 2, false);
+            let b = Decimal::new(3, 0, false);
+
+            assert_eq!(
+                a.div_with(b, RoundingMode::HalfEven),
+                Decimal::new(67, 2, false)
+            );
+            assert_eq!(
+                a.div_with(b, RoundingMode::TowardZero),
+                Decimal::new(66, 2, false)
+            );
+        }
+
+        {
+            // test: 0.5 / 2 = 0.25 = 0.2 rounded half even, 0.3 rounded half up
+            let a = Decimal::new(5, 1, false);
+            let b = Decimal::new(2, 0, false);
+
+            assert_eq!(
+                a.div_with(b, RoundingMode::HalfEven),
+                Decimal::new(2, 1, false)
+            );
+            assert_eq!(
+                a.div_with(b, RoundingMode::HalfUp),
+                Decimal::new(3, 1, false)
+            );
+        }
+
+        {
+            // test: -1 / 3 = -0.333 = -0.4 rounded away from zero, -0.3 rounded half down
+            let a = Decimal::new(10, 1, true);
+            let b = Decimal::new(3, 0, false);
+
+            assert_eq!(
+                a.div_with(b, RoundingMode::AwayFromZero),
+                Decimal::new(4, 1, true)
+            );
+            assert_eq!(
+                a.div_with(b, RoundingMode::HalfDown),
+                Decimal::new(3, 1, true)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_with_zero() {
+        let a = Decimal::new(1, 0, false);
+        let b = Decimal::new(0, 0, false);
+
+        a.div_with(b, RoundingMode::HalfEven);
+    }
+
+    #[test]
+    fn test_div_with_u128_max() {
+        {
+            // test: u128::MAX / 1.0 at scale 38, the dividend is widened beyond U192
+            let a = Decimal::new(u128::MAX, 38, false);
+            let one = Decimal::new(10u128.pow(38), 38, false);
+
+            assert_eq!(a.div_with(one, RoundingMode::HalfEven), a);
+        }
+
+        {
+            // test: u128::MAX / 2.0 at scale 1 = 170141183460469231731687303715884105727.5
+            let a = Decimal::new(u128::MAX, 1, true);
+            let two = Decimal::new(20, 1, false);
+
+            assert_eq!(
+                a.div_with(two, RoundingMode::HalfUp),
+                Decimal::new(u128::MAX / 2 + 1, 1, true)
+            );
+            assert_eq!(
+                a.div_with(two, RoundingMode::Ceiling),
+                Decimal::new(u128::MAX / 2, 1, true)
+            );
+        }
+    }
+
+    #[test]
+    fn test_div_with_big_decimal() {
+        {
+            // test: 3.5 / 2 = 1.75 = 1.8 rounded half even
+            let a = BigDecimal::new(U192::from(35), 1, false);
+            let b = BigDecimal::new(U192::from(2), 0, false);
+
+            assert_eq!(
+                a.div_with(b, RoundingMode::HalfEven),
+                BigDecimal::new(U192::from(18), 1, false)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_div_with_never_negative_zero(
+            a in 0..1_000_000_000u128, a_negative: bool,
+            b in 1..1_000_000_000u128, b_negative: bool,
+            scale in 0..=9u8,
+            mode in prop::sample::select(vec![
+                RoundingMode::Floor,
+                RoundingMode::Ceiling,
+                RoundingMode::TowardZero,
+                RoundingMode::AwayFromZero,
+                RoundingMode::HalfUp,
+                RoundingMode::HalfDown,
+                RoundingMode::HalfEven,
+            ]),
+        ) {
+            let a = Decimal::new(a, scale, a_negative);
+            let b = Decimal::new(b, scale, b_negative);
+
+            let actual = a.div_with(b, mode);
+            assert!(!(actual.negative && actual.is_zero()), "{:?}", actual);
+
+            let actual = BigDecimal::from(a).div_with(BigDecimal::from(b), mode);
+            assert!(!(actual.negative && actual.value.is_zero()), "{:?}", actual);
+        }
+    }
+}
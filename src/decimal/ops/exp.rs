@@ -0,0 +1,433 @@
+use crate::big_dec;
+use crate::decimal::core::uint::U192;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::{BigDecimal, Decimal, BIG_COMPUTE_SCALE};
+
+pub trait Exp<T>: Sized {
+    fn exp(self) -> Result<Self, ErrorCode>;
+    fn exp2(self) -> Result<Self, ErrorCode>;
+    fn exp10(self) -> Result<Self, ErrorCode>;
+}
+
+/// ln(2) = 0.693147180559945309417232121458176568075500134360255254120680009...
+const LN_2: BigDecimal = big_dec!(0.693147180559945309417232121);
+
+/// ln(10) = 2.302585092994045684017991454684364207601101488628772976033327900...
+const LN_10: BigDecimal = big_dec!(2.302585092994045684017991455);
+
+/// Internal scale of the Taylor series, [BIG_COMPUTE_SCALE] plus 9 guard digits to absorb
+/// the truncation of every term.
+const SERIES_SCALE: u8 = 27;
+
+/// Largest integer part of an exponent that can still produce a representable result,
+/// e^1000 is far beyond the range of U192 at any scale.
+const MAX_EXPONENT: u64 = 1000;
+
+/// Calculate the exponential of a [Decimal], by way of [BigDecimal] to keep 18 digits of
+/// precision before truncating the result back to the scale of the [Decimal].
+impl Exp<Decimal> for Decimal {
+    /// Calculate e^x.
+    fn exp(self) -> Result<Self, ErrorCode> {
+        to_decimal(BigDecimal::from(self).exp()?)
+    }
+
+    /// Calculate 2^x.
+    fn exp2(self) -> Result<Self, ErrorCode> {
+        to_decimal(BigDecimal::from(self).exp2()?)
+    }
+
+    /// Calculate 10^x.
+    fn exp10(self) -> Result<Self, ErrorCode> {
+        to_decimal(BigDecimal::from(self).exp10()?)
+    }
+}
+
+/// Calculate the exponential of a [BigDecimal] to 18 significant digits, truncating the result
+/// back to the scale of the [BigDecimal].
+impl Exp<BigDecimal> for BigDecimal {
+    /// Calculate e^x, reduced to e^x = 2^k · e^r where |x| = k·ln(2) + r.
+    fn exp(self) -> Result<Self, ErrorCode> {
+        let x = match exponent_abs(self) {
+            Some(x) => x,
+            None => return exp_result(U192::zero(), &|_| None, self),
+        };
+        let ln_2 = LN_2.value;
+
+        // |x| = k·ln(2) + r
+        let k = x.checked_div(ln_2).expect("k");
+        let r = x
+            .checked_sub(k.checked_mul(ln_2).expect("k_ln_2"))
+            .expect("r");
+
+        // e^|x| = 2^k · e^r
+        exp_result(exp_series(r), &|value| shl(value, k.low_u32()), self)
+    }
+
+    /// Calculate 2^x, reduced to 2^x = 2^n · e^(f·ln(2)) where |x| = n + f.
+    fn exp2(self) -> Result<Self, ErrorCode> {
+        let x = match exponent_abs(self) {
+            Some(x) => x,
+            None => return exp_result(U192::zero(), &|_| None, self),
+        };
+        let one = series_one();
+
+        // |x| = n + f
+        let n = x.checked_div(one).expect("n");
+        let f = x.checked_rem(one).expect("f");
+        let r = f
+            .checked_mul(LN_2.value)
+            .expect("r")
+            .checked_div(one)
+            .expect("r");
+
+        // 2^|x| = 2^n · e^r
+        exp_result(exp_series(r), &|value| shl(value, n.low_u32()), self)
+    }
+
+    /// Calculate 10^x, reduced to 10^x = 10^n · e^(f·ln(10)) where |x| = n + f.
+    fn exp10(self) -> Result<Self, ErrorCode> {
+        let x = match exponent_abs(self) {
+            Some(x) => x,
+            None => return exp_result(U192::zero(), &|_| None, self),
+        };
+        let one = series_one();
+
+        // |x| = n + f
+        let n = x.checked_div(one).expect("n");
+        let f = x.checked_rem(one).expect("f");
+        let r = f
+            .checked_mul(LN_10.value)
+            .expect("r")
+            .checked_div(one)
+            .expect("r");
+
+        // 10^|x| = 10^n · e^r
+        let power = U192::from(10u64).checked_pow(n);
+        exp_result(exp_series(r), &|value| value.checked_mul(power?), self)
+    }
+}
+
+/// Convert a [BigDecimal] result back to a [Decimal], if its value fits.
+fn to_decimal(big_decimal: BigDecimal) -> Result<Decimal, ErrorCode> {
+    Ok(Decimal::new(
+        big_decimal
+            .value
+            .try_into()
+            .map_err(|_| ErrorCode::ExceedsRange)?,
+        big_decimal.scale,
+        big_decimal.negative,
+    ))
+}
+
+/// One at [SERIES_SCALE].
+fn series_one() -> U192 {
+    U192::from(10u128.pow(SERIES_SCALE.into()))
+}
+
+/// Ten to the number of guard digits between [SERIES_SCALE] and [BIG_COMPUTE_SCALE].
+fn guard() -> U192 {
+    U192::from(10u128.pow((SERIES_SCALE - BIG_COMPUTE_SCALE).into()))
+}
+
+/// Convert the absolute value of an exponent to [SERIES_SCALE], or None when its integer
+/// part is too large for any result to be representable.
+fn exponent_abs(x: BigDecimal) -> Option<U192> {
+    if x.to_scale(0).value > U192::from(MAX_EXPONENT) {
+        return None;
+    }
+
+    Some(x.to_scale(SERIES_SCALE).value)
+}
+
+/// Calculate e^r at [SERIES_SCALE] for 0 <= r < ln(10), summing the Taylor series
+/// e^r = 1 + r + r^2/2! + r^3/3! + ... until the terms truncate to zero.
+fn exp_series(r: U192) -> U192 {
+    let one = series_one();
+    let mut sum = one;
+    let mut term = one;
+    let mut n = U192::from(1u64);
+
+    loop {
+        term = term
+            .checked_mul(r)
+            .expect("term")
+            .checked_div(n.checked_mul(one).expect("n"))
+            .expect("term");
+
+        if term.is_zero() {
+            break;
+        }
+
+        sum = sum.checked_add(term).expect("sum");
+        n = n.checked_add(U192::from(1u64)).expect("n");
+    }
+
+    sum
+}
+
+/// Multiply by 2^k, or None when the result does not fit.
+fn shl(value: U192, k: u32) -> Option<U192> {
+    if k < value.leading_zeros() {
+        Some(value << k)
+    } else {
+        None
+    }
+}
+
+/// Build the result of b^x from the factors of b^|x| = m · e^r, given as e^r at
+/// [SERIES_SCALE] and a function multiplying by m that returns None when b^|x| is out of
+/// range. The result is inverted for a negative x and truncated to the scale of x.
+fn exp_result(
+    series: U192,
+    multiply: &dyn Fn(U192) -> Option<U192>,
+    x: BigDecimal,
+) -> Result<BigDecimal, ErrorCode> {
+    let value = if x.is_negative() {
+        // b^-|x| = 1/b^|x|, which truncates to zero when b^|x| does not fit
+        match multiply(series) {
+            Some(value) => series_one()
+                .checked_mul(U192::from(10u128.pow(BIG_COMPUTE_SCALE.into())))
+                .expect("one")
+                .checked_div(value)
+                .expect("div"),
+            None => U192::zero(),
+        }
+    } else {
+        // drop the guard digits after multiplying where possible, or before for large results
+        multiply(series)
+            .map(|value| value.checked_div(guard()).expect("guard"))
+            .or_else(|| multiply(series.checked_div(guard()).expect("guard")))
+            .ok_or(ErrorCode::ExceedsRange)?
+    };
+
+    let value = if x.scale > BIG_COMPUTE_SCALE {
+        value
+            .checked_mul(U192::from(10u128.pow((x.scale - BIG_COMPUTE_SCALE).into())))
+            .ok_or(ErrorCode::ExceedsRange)?
+    } else {
+        value
+            .checked_div(U192::from(10u128.pow((BIG_COMPUTE_SCALE - x.scale).into())))
+            .expect("scaled_down")
+    };
+
+    Ok(BigDecimal::new(value, x.scale, false))
+}
+
+#[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::{Exp, Ln};
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_exp() {
+        // e^0 = 1
+        {
+            let actual = Decimal::new(0, 12, false).exp().unwrap();
+            let expected = Decimal::new(1_000000000000, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^1 = 2.718281828459045235360287471352662497757247093699959574966...
+        {
+            let actual = Decimal::new(1_000000000000, 12, false).exp().unwrap();
+            let expected = Decimal::new(2_718281828459, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^-1 = 0.367879441171442321595523770161460867445811131031767834507...
+        {
+            let actual = Decimal::new(1_000000000000, 12, true).exp().unwrap();
+            let expected = Decimal::new(367879441171, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^0.810930216211 = 2.249999999988010281098972925601390253550888645198687084989...
+        {
+            let actual = Decimal::new(810930216211, 12, false).exp().unwrap();
+            let expected = Decimal::new(2_249999999988, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^42.5 = 2867579591680571559.563933018785474168663702986955106541073...
+        {
+            let actual = Decimal::new(42_500000, 6, false).exp().unwrap();
+            let expected = Decimal::new(2867579591680571559_563933, 6, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^-42.5 = 0.000000000000000000353263...
+        {
+            let actual = Decimal::new(42_500000, 6, true).exp().unwrap();
+            let expected = Decimal::new(0, 6, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^1000 does not fit
+        {
+            let actual = Decimal::new(1000_000000, 6, false).exp();
+            assert!(matches!(actual, Err(ErrorCode::ExceedsRange)));
+        }
+
+        // e^-1000 = 0
+        {
+            let actual = Decimal::new(1000_000000, 6, true).exp().unwrap();
+            let expected = Decimal::new(0, 6, false);
+            assert_eq!(actual, expected);
+        }
+
+        // e^100 = 2.688117141816135448412625551580013587361111877374192241519e43 does not fit
+        // in a u128 at scale 6
+        {
+            let actual = Decimal::new(100_000000, 6, false).exp();
+            assert!(matches!(actual, Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_exp2() {
+        // 2^0 = 1
+        {
+            let actual = Decimal::new(0, 12, false).exp2().unwrap();
+            let expected = Decimal::new(1_000000000000, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 2^3 = 8
+        {
+            let actual = Decimal::new(3_000000000000, 12, false).exp2().unwrap();
+            let expected = Decimal::new(8_000000000000, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 2^-3 = 0.125
+        {
+            let actual = Decimal::new(3_000000000000, 12, true).exp2().unwrap();
+            let expected = Decimal::new(125000000000, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 2^0.5 = 1.414213562373095048801688724209698078569671875376948073176...
+        {
+            let actual = Decimal::new(500000000000, 12, false).exp2().unwrap();
+            let expected = Decimal::new(1_414213562373, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 2^24.136862861344 = 18446744.073611713012739860696756079396011712751038620622...
+        {
+            let actual = Decimal::new(24_136862861344, 12, false).exp2().unwrap();
+            let expected = Decimal::new(18446744_073611, 6, false);
+            assert_eq!(actual.to_scale(6), expected);
+        }
+    }
+
+    #[test]
+    fn test_exp10() {
+        // 10^0 = 1
+        {
+            let actual = Decimal::new(0, 8, false).exp10().unwrap();
+            let expected = Decimal::new(1_00000000, 8, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 10^2 = 100
+        {
+            let actual = Decimal::new(2_00000000, 8, false).exp10().unwrap();
+            let expected = Decimal::new(100_00000000, 8, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 10^-2 = 0.01
+        {
+            let actual = Decimal::new(2_00000000, 8, true).exp10().unwrap();
+            let expected = Decimal::new(1000000, 8, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 10^0.041392685158 = 1.099999999999430006975600832029774716123910245074726413157...
+        {
+            let actual = Decimal::new(41392685158, 12, false).exp10().unwrap();
+            let expected = Decimal::new(1_099999999999, 12, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 10^39 does not fit in a u128
+        {
+            let actual = Decimal::new(39, 0, false).exp10();
+            assert!(matches!(actual, Err(ErrorCode::ExceedsRange)));
+        }
+    }
+
+    #[test]
+    fn test_exp_big_decimal() {
+        // e^1 = 2.718281828459045235
+        {
+            let actual = BigDecimal::one().exp().unwrap();
+            let expected = BigDecimal::new(U192::from(2_718281828459045235u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 2^100 = 1267650600228229401496703205376
+        {
+            let actual = BigDecimal::new(U192::from(100u128), 0, false)
+                .exp2()
+                .unwrap();
+            let expected =
+                BigDecimal::new(U192::from(1267650600228229401496703205376u128), 0, false);
+            assert_eq!(actual, expected);
+        }
+
+        // 10^-18 = 0.000000000000000001
+        {
+            let actual = BigDecimal::new(U192::from(18_000000000000000000u128), 18, true)
+                .exp10()
+                .unwrap();
+            let expected = BigDecimal::new(U192::from(1u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_full_range_exp_ln(
+            x in 1u128..1_000_000_000_000_000u128, // 0.000000001 .. 1,000,000.000000000
+        ) {
+            let scale = 9; // decimal places
+            let precision = 3; // accuracy +/- 0.000000002
+            let decimal = Decimal::new(x, scale, false);
+
+            // e^ln(x) == x
+            {
+                let actual = decimal.ln().unwrap().exp().unwrap();
+                let tolerance = precision + x / 100_000_000;
+                let difference = actual.value.abs_diff(x).lt(&tolerance);
+
+                assert!(difference, "exp compare\n{}\n{}", actual, decimal);
+            }
+        }
+
+        #[test]
+        fn test_full_range_exp(
+            x in 0u128..40_000000000u128, // 0.000000000 .. 40.000000000
+            negative in proptest::bool::ANY,
+        ) {
+            let scale = 9; // decimal places
+            let decimal = Decimal::new(x, scale, negative);
+            let x_f64: f64 = decimal.into();
+            let den_f64: f64 = decimal.denominator() as f64;
+
+            // f64 exp == Decimal exp, to 14 significant digits
+            {
+                let exp_f64 = x_f64.exp() * den_f64;
+                let exp_decimal = decimal.exp().unwrap().value as f64;
+                let difference = (exp_f64 - exp_decimal).abs() <= exp_f64 * 1e-14 + 1.0;
+
+                assert!(difference, "exp compare\n{}\n{}\n{}", exp_f64, exp_decimal, decimal);
+            }
+        }
+    }
+}
@@ -1,27 +1,54 @@
-use crate::decimal::ops::{Div, Log2};
-use crate::decimal::{Decimal, COMPUTE_SCALE};
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::DecimalStorage;
 use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::log_2::{big_log2, impl_log, log_result, LOG_SCALE};
+use crate::decimal::ops::{Div, Log2};
+use crate::decimal::wide::{Decimal256, Decimal512};
+use crate::decimal::{BigDecimal, Decimal, Decimal64, GenericDecimal};
+use crate::{big_dec, dec};
 
 pub trait Ln<T>: Sized {
     fn ln(self) -> Result<Self, ErrorCode>;
 }
 
+/// Calculate ln(x) of a [Decimal] at [COMPUTE_SCALE](crate::decimal::COMPUTE_SCALE), truncated
+/// to the scale of x.
 impl Ln<Decimal> for Decimal {
     fn ln(self) -> Result<Self, ErrorCode> {
-        let log2_x = self.to_compute_scale().log2().expect("log_2");
+        let log2_x = self.to_compute_scale().log2()?;
 
         // 1.4426950408889634073599246810018921374266459541529859341354494069
-        let log2_e = Decimal::new(1_442695040888u128, COMPUTE_SCALE, false);
+        let log2_e = dec!(1.442695040888);
 
         // ln(x) = log2(x) / log2(e)
         Ok(log2_x.div(log2_e).to_scale(self.scale))
     }
 }
 
+/// Calculate ln(x) of a [GenericDecimal] to 18 decimal places, rounded to the scale of x.
+fn generic_ln<T: DecimalStorage>(x: GenericDecimal<T>) -> Result<GenericDecimal<T>, ErrorCode> {
+    let (log2_x, negative) = big_log2(x)?;
+
+    // 1.4426950408889634073599246810018921374266459541529859341354494069
+    let log2_e = big_dec!(1.442695040888963407359924681).value;
+
+    // ln(x) = log2(x) / log2(e)
+    let ln_x = log2_x
+        .checked_mul(U192::from(10u128.pow(LOG_SCALE.into())))
+        .expect("mul")
+        .checked_div(log2_e)
+        .expect("div");
+
+    log_result(ln_x, negative, x.scale)
+}
+
+impl_log!(Ln, ln, generic_ln);
+
 #[cfg(test)]
 mod tests {
-    use crate::decimal::ops::Ln;
-    use crate::decimal::Decimal;
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::ops::{Exp, Ln};
+    use crate::decimal::{BigDecimal, Decimal};
     use proptest::prelude::*;
 
     #[test]
@@ -61,7 +88,7 @@ mod tests {
             assert_eq!(actual, expected);
         }
 
-        // ln(0.9) = -0.105360
+        // ln(0.9) = -0.105361
         {
             let decimal = Decimal::new(900000u128, 6, false);
             let actual = decimal.ln().unwrap();
@@ -69,7 +96,7 @@ mod tests {
             assert_eq!(actual, expected);
         }
 
-        // ln(0.9) = -0.105360515652
+        // ln(0.9) = -0.105360515658
         {
             let decimal = Decimal::new(900_000_000_000u128, 12, false);
             let actual = decimal.ln().unwrap();
@@ -77,7 +104,7 @@ mod tests {
             assert_eq!(actual, expected);
         }
 
-        // ln(0.1) = -2.302585092990
+        // ln(0.1) = -2.302585092994
         {
             let decimal = Decimal::new(100000000000u128, 12, false);
             let actual = decimal.ln().unwrap();
@@ -85,7 +112,7 @@ mod tests {
             assert_eq!(actual, expected);
         }
 
-        // ln(10) = 2.302585092990
+        // ln(10) = 2.302585092994
         {
             let decimal = Decimal::new(10_000000000000, 12, false);
             let actual = decimal.ln().unwrap();
@@ -94,7 +121,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_log_e_big_decimal() {
+        // ln(2.25) = 0.8109302162163287639560262309286982731439808469249883952280
+        {
+            let decimal = BigDecimal::new(U192::from(2_250000000000000000u128), 18, false);
+            let actual = decimal.ln().unwrap();
+            let expected = BigDecimal::new(U192::from(810930216216328764u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // ln(0.810930216216328764) = -0.209573275157934673062428397029709797294187962681
+        {
+            let decimal = BigDecimal::new(U192::from(810930216216328764u128), 18, false);
+            let actual = decimal.ln().unwrap();
+            let expected = BigDecimal::new(U192::from(209573275157934673u128), 18, true);
+            assert_eq!(actual, expected);
+        }
+
+        // ln(0.000000000000000001) = -41.446531673892822312323846184318555736819826795
+        {
+            let decimal = BigDecimal::new(U192::from(1u128), 18, false);
+            let actual = decimal.ln().unwrap();
+            let expected = BigDecimal::new(U192::from(41_446531673892822312u128), 18, true);
+            assert_eq!(actual, expected);
+        }
+
+        // ln(6277101735386680763835789423207666416102355444464034512895)
+        // = 133.084258667509499408108567319969901070496025797169008791170
+        {
+            let decimal = BigDecimal::new(U192::MAX, 0, false);
+            let actual = decimal.ln().unwrap();
+            let expected = BigDecimal::new(U192::from(133u128), 0, false);
+            assert_eq!(actual, expected);
+        }
+
+        // ln(1) = 0
+        {
+            let decimal = BigDecimal::one();
+            let actual = decimal.ln().unwrap();
+            let expected = BigDecimal::zero();
+            assert_eq!(actual, expected);
+        }
+    }
+
     proptest! {
+        #[test]
+        fn test_full_u128_range_ln_big_decimal(
+            x in 1..u128::MAX, // 0.000000000000000001 .. 340,282,366,920,938,463,463.374607431768211455
+        ) {
+            let scale = 18; // decimal places
+            let decimal = BigDecimal::new(U192::from(x), scale, false);
+
+            // e^ln(x) == x, to 17 significant digits
+            {
+                let actual = decimal.ln().unwrap().exp().unwrap().value;
+                let tolerance = U192::from(x / 100_000_000_000_000_000 + 2);
+                let difference = if actual > decimal.value {
+                    actual - decimal.value
+                } else {
+                    decimal.value - actual
+                };
+
+                assert!(difference < tolerance, "ln compare\n{}\n{}", actual, decimal.value);
+            }
+        }
+
         #[test]
         fn test_full_u64_range_ln(
             lhs in 1..u64::MAX, // 1.000000 .. 18,446,744,073,709.551615
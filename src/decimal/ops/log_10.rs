@@ -1,11 +1,18 @@
-use crate::decimal::ops::{Div, Log2};
-use crate::decimal::{Decimal, COMPUTE_SCALE};
+use crate::dec;
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::DecimalStorage;
 use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::log_2::{big_log2, impl_log, log_result, LOG2_10, LOG_SCALE};
+use crate::decimal::ops::{Div, Log2};
+use crate::decimal::wide::{Decimal256, Decimal512};
+use crate::decimal::{BigDecimal, Decimal, Decimal64, GenericDecimal, COMPUTE_SCALE};
 
 pub trait Log10<T>: Sized {
     fn log10(self) -> Result<Self, ErrorCode>;
 }
 
+/// Calculate log10(x) of a [Decimal] at [COMPUTE_SCALE], exact for powers of ten and
+/// truncated to the scale of x.
 impl Log10<Decimal> for Decimal {
     fn log10(self) -> Result<Self, ErrorCode> {
         let scale = self.scale;
@@ -51,19 +58,36 @@ impl Log10<Decimal> for Decimal {
             Ok(Decimal::new(value, x.scale, negative).to_scale(scale))
         } else {
             // log2(10) = 3.3219280948873623478703194294893901758648313930245806120547563958...
-            let log2_10 = Decimal::new(3_321928094887u128, COMPUTE_SCALE, false);
+            let log2_10 = dec!(3.321928094887);
 
-            let log2_x = self.log2().expect("log2_x");
+            let log2_x = self.log2()?;
             // log2(x) / log2(10)
             Ok(log2_x.div(log2_10).to_scale(self.scale))
         }
     }
 }
 
+/// Calculate log10(x) of a [GenericDecimal] to 18 decimal places, rounded to the scale of x.
+fn generic_log10<T: DecimalStorage>(x: GenericDecimal<T>) -> Result<GenericDecimal<T>, ErrorCode> {
+    let (log2_x, negative) = big_log2(x)?;
+
+    // log2(x) / log2(10)
+    let log10_x = log2_x
+        .checked_mul(U192::from(10u128.pow(LOG_SCALE.into())))
+        .expect("mul")
+        .checked_div(LOG2_10.value)
+        .expect("div");
+
+    log_result(log10_x, negative, x.scale)
+}
+
+impl_log!(Log10, log10, generic_log10);
+
 #[cfg(test)]
 mod test {
+    use crate::decimal::core::uint::U192;
     use crate::decimal::ops::Log10;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_log10() {
@@ -102,4 +126,46 @@ mod test {
             assert_eq!(actual, expected);
         }
     }
+
+    #[test]
+    fn test_log10_big_decimal() {
+        // log10(1) = 0
+        {
+            let actual = BigDecimal::one().log10().unwrap();
+            let expected = BigDecimal::zero();
+            assert_eq!(actual, expected);
+        }
+
+        // log10(1000) = 3
+        {
+            let decimal = BigDecimal::new(U192::from(1000_000000000000000000u128), 18, false);
+            let actual = decimal.log10().unwrap();
+            let expected = BigDecimal::new(U192::from(3_000000000000000000u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // log10(0.000000000000000001) = -18
+        {
+            let decimal = BigDecimal::new(U192::from(1u128), 18, false);
+            let actual = decimal.log10().unwrap();
+            let expected = BigDecimal::new(U192::from(18_000000000000000000u128), 18, true);
+            assert_eq!(actual, expected);
+        }
+
+        // log10(1.1) = 0.0413926851582250407501999712430242417067021904664530945965390186...
+        {
+            let decimal = BigDecimal::new(U192::from(1_100000000000000000u128), 18, false);
+            let actual = decimal.log10().unwrap();
+            let expected = BigDecimal::new(U192::from(41392685158225041u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // log10(18446744.073709551615) = 7.26591972249479649366...
+        {
+            let decimal = BigDecimal::new(U192::from(u64::MAX), 12, false);
+            let actual = decimal.log10().unwrap();
+            let expected = BigDecimal::new(U192::from(7_265919722495u128), 12, false);
+            assert_eq!(actual, expected);
+        }
+    }
 }
@@ -1,15 +1,33 @@
-use crate::decimal::{Decimal, COMPUTE_SCALE};
+use crate::big_dec;
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::DecimalStorage;
 use crate::decimal::errors::ErrorCode;
+use crate::decimal::wide::{Decimal256, Decimal512};
+use crate::decimal::{BigDecimal, Decimal, Decimal64, GenericDecimal};
 
 pub trait Log2<T>: Sized {
     fn log2(self) -> Result<Self, ErrorCode>;
 }
 
+/// Internal scale of [GenericDecimal] logarithms, [BIG_COMPUTE_SCALE](crate::decimal::BIG_COMPUTE_SCALE)
+/// plus 9 guard digits to absorb the truncation of every iteration.
+pub(crate) const LOG_SCALE: u8 = 27;
+
+/// log2(10) = 3.3219280948873623478703194294893901758648313930245806120547563958...
+pub(crate) const LOG2_10: BigDecimal = big_dec!(3.321928094887362347870319429);
+
+/// Calculate log2(x) of a [Decimal] at the scale of x, truncating every iteration.
 impl Log2<Decimal> for Decimal {
     fn log2(self) -> Result<Self, ErrorCode> {
-        let mut x: u128 = self.value;
+        if self.is_negative() {
+            return Err(ErrorCode::SignedDecimalsNotSupported);
+        }
+
+        if self.is_zero() {
+            return Err(ErrorCode::ExceedsRange);
+        }
 
-        assert!(x > 0, "must be greater than zero");
+        let mut x: u128 = self.value;
 
         let scale: u128 = 10u128.checked_pow(self.scale as u32).expect("scale");
 
@@ -35,7 +53,7 @@ impl Log2<Decimal> for Decimal {
 
         // if y = 1, then the algorithm is done, and the fractional part is zero
         if y == scale {
-            return Ok(Decimal::new(result, COMPUTE_SCALE, negative).to_scale(self.scale));
+            return Ok(Decimal::new(result, self.scale, negative));
         }
 
         // calculate fractional part via iterative approximation.
@@ -59,14 +77,144 @@ impl Log2<Decimal> for Decimal {
             z >>= 1;
         }
 
-        Ok(Decimal::new(result, COMPUTE_SCALE, negative).to_scale(self.scale))
+        Ok(Decimal::new(result, self.scale, negative))
+    }
+}
+
+/// Calculate log2(x) of a [GenericDecimal] to 18 decimal places, rounded to the scale of x.
+fn generic_log2<T: DecimalStorage>(x: GenericDecimal<T>) -> Result<GenericDecimal<T>, ErrorCode> {
+    let (log2_x, negative) = big_log2(x)?;
+
+    log_result(log2_x, negative, x.scale)
+}
+
+/// Implements a logarithm of every width but [Decimal], which keeps its own algorithm at
+/// [COMPUTE_SCALE](crate::decimal::COMPUTE_SCALE), through the shared function at [LOG_SCALE].
+macro_rules! impl_log {
+    ($trait:ident, $method:ident, $shared:ident) => {
+        impl_log!($trait, $method, $shared, Decimal64, BigDecimal, Decimal256, Decimal512);
+    };
+    ($trait:ident, $method:ident, $shared:ident, $($t:ty),+) => {
+        $(
+            impl $trait<$t> for $t {
+                fn $method(self) -> Result<Self, ErrorCode> {
+                    $shared(self)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_log;
+
+impl_log!(Log2, log2, generic_log2);
+
+/// Calculate the absolute value and sign of log2(x) at [LOG_SCALE] for a [GenericDecimal] over
+/// its full range, as log2(x) = log2(value) - scale·log2(10).
+pub(crate) fn big_log2<T: DecimalStorage>(x: GenericDecimal<T>) -> Result<(U192, bool), ErrorCode> {
+    if x.is_negative() {
+        return Err(ErrorCode::SignedDecimalsNotSupported);
+    }
+
+    if x.is_zero() {
+        return Err(ErrorCode::ExceedsRange);
+    }
+
+    let one = U192::from(10u128.pow(LOG_SCALE.into()));
+
+    // integer part of the logarithm is most significant bit n
+    let n = x.value.bits() - 1;
+
+    let mut result = U192::from(n).checked_mul(one).expect("mul");
+
+    // y = value / 2^n, keeping at most 100 significant bits of value
+    let mut y = if n <= 100 {
+        U192::from(x.value.low_u128())
+            .checked_mul(one)
+            .expect("mul")
+            >> n
+    } else {
+        U192::from(x.value.shift_right(n - 100).low_u128())
+            .checked_mul(one)
+            .expect("mul")
+            >> 100
+    };
+
+    // calculate fractional part via iterative approximation.
+    // https://en.wikipedia.org/wiki/Binary_logarithm#Iterative_approximation
+    let two = one.checked_mul(U192::from(2u64)).expect("two");
+    let mut z = one >> 1;
+
+    while y != one && !z.is_zero() {
+        // y = y^2 / scale;
+        y = y
+            .checked_mul(y)
+            .expect("checked_mul")
+            .checked_div(one)
+            .expect("checked_div");
+
+        // if y^2 >= 2
+        if y >= two {
+            // result += 2^(-z)
+            result = result.checked_add(z).expect("checked_add");
+            y >>= 1;
+        }
+
+        // z /= 2
+        z >>= 1;
+    }
+
+    // log2(x) = log2(value) - log2(10^scale)
+    let log2_denominator = LOG2_10
+        .value
+        .checked_mul(U192::from(x.scale))
+        .expect("log2_denominator");
+
+    if result >= log2_denominator {
+        Ok((result - log2_denominator, false))
+    } else {
+        Ok((log2_denominator - result, true))
     }
 }
 
+/// Round the absolute value of a logarithm at [LOG_SCALE] to a [GenericDecimal] at the given
+/// scale, returning [ErrorCode::ExceedsRange] if it does not fit. Logarithms of any backing
+/// integer stay below 2^11, so the value at [LOG_SCALE] always fits in a u128.
+pub(crate) fn log_result<T: DecimalStorage>(
+    value: U192,
+    negative: bool,
+    scale: u8,
+) -> Result<GenericDecimal<T>, ErrorCode> {
+    let value = if scale >= LOG_SCALE {
+        u128::try_from(value)
+            .ok()
+            .and_then(T::checked_from_u128)
+            .zip(T::checked_pow10((scale - LOG_SCALE).into()))
+            .and_then(|(value, factor)| value.checked_mul(factor))
+    } else {
+        let denominator = U192::from(10u128.pow((LOG_SCALE - scale).into()));
+        let value = value
+            .checked_add(denominator >> 1)
+            .expect("round")
+            .checked_div(denominator)
+            .expect("scaled_down");
+
+        u128::try_from(value).ok().and_then(T::checked_from_u128)
+    };
+
+    Ok(GenericDecimal::new(
+        value.ok_or(ErrorCode::ExceedsRange)?,
+        scale,
+        negative,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::errors::ErrorCode;
     use crate::decimal::ops::Log2;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_log2() {
@@ -80,10 +228,77 @@ mod tests {
 
         // log2(18446744.073709551615) = 24.1368628613516518255
         {
-            let decimal = Decimal::new(18446744073709551615, 12, false); // 2.25
+            let decimal = Decimal::new(18446744073709551615, 12, false); // 18446744.073709551615
             let actual = decimal.log2().unwrap();
             let expected = Decimal::new(24_136862861344, 12, false);
             assert_eq!(actual, expected);
         }
+
+        // log2(0.25) = -2 at scale 6, computed at the scale of x
+        {
+            let decimal = Decimal::new(250000, 6, false);
+            let actual = decimal.log2().unwrap();
+            let expected = Decimal::new(2_000000, 6, true);
+            assert_eq!(actual, expected);
+        }
+
+        // log2(0) is out of range and log2(-1) is not supported
+        {
+            let decimal = Decimal::new(0, 12, false);
+            assert_eq!(decimal.log2(), Err(ErrorCode::ExceedsRange));
+
+            let decimal = Decimal::new(1, 0, true);
+            assert_eq!(decimal.log2(), Err(ErrorCode::SignedDecimalsNotSupported));
+        }
+    }
+
+    #[test]
+    fn test_log2_big_decimal() {
+        // log2(2.25) = 1.1699250014423123629074778878956330175196288153849621209115
+        {
+            let decimal = BigDecimal::new(U192::from(2_250000000000000000u128), 18, false);
+            let actual = decimal.log2().unwrap();
+            let expected = BigDecimal::new(U192::from(1_169925001442312363u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // log2(0.5) = -1
+        {
+            let decimal = BigDecimal::new(U192::from(500000000000000000u128), 18, false);
+            let actual = decimal.log2().unwrap();
+            let expected = BigDecimal::new(U192::from(1_000000000000000000u128), 18, true);
+            assert_eq!(actual, expected);
+        }
+
+        // log2(1) = 0
+        {
+            let decimal = BigDecimal::new(U192::from(1_000000000000000000u128), 18, false);
+            let actual = decimal.log2().unwrap();
+            let expected = BigDecimal::new(U192::from(0u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // log2(18446744073709551615) = 63.999999999999999999921791345121706111...
+        {
+            let decimal = BigDecimal::new(U192::from(u64::MAX), 0, false).to_scale(18);
+            let actual = decimal.log2().unwrap();
+            let expected = BigDecimal::new(U192::from(64_000000000000000000u128), 18, false);
+            assert_eq!(actual, expected);
+        }
+
+        // log2(0) is out of range
+        {
+            let decimal = BigDecimal::new(U192::from(0u128), 18, false);
+            assert!(matches!(decimal.log2(), Err(ErrorCode::ExceedsRange)));
+        }
+
+        // log2(-1) is not supported
+        {
+            let decimal = BigDecimal::new(U192::from(1u128), 0, true);
+            assert!(matches!(
+                decimal.log2(),
+                Err(ErrorCode::SignedDecimalsNotSupported)
+            ));
+        }
     }
 }
@@ -1,29 +1,58 @@
 pub(crate) mod add;
+pub(crate) mod align;
 pub(crate) mod big_div;
 pub(crate) mod big_mul;
+pub(crate) mod checked_add;
+pub(crate) mod checked_div;
+pub(crate) mod checked_div_up;
+pub(crate) mod checked_mul;
+pub(crate) mod checked_mul_up;
+pub(crate) mod checked_sub;
 pub(crate) mod div;
 pub(crate) mod div_up;
+pub(crate) mod div_with;
+pub(crate) mod exp;
 pub(crate) mod ln;
 pub(crate) mod log_10;
 pub(crate) mod log_2;
 pub(crate) mod mul;
 pub(crate) mod mul_up;
+pub(crate) mod mul_with;
 pub(crate) mod neg;
+pub(crate) mod operators;
 pub(crate) mod pow;
+pub(crate) mod saturating_add;
+pub(crate) mod saturating_div;
+pub(crate) mod saturating_mul;
+pub(crate) mod saturating_sub;
 pub(crate) mod sqrt;
 pub(crate) mod sub;
 
 pub use add::*;
+pub use align::*;
 pub use big_div::*;
 pub use big_mul::*;
+pub use checked_add::*;
+pub use checked_div::*;
+pub use checked_div_up::*;
+pub use checked_mul::*;
+pub use checked_mul_up::*;
+pub use checked_sub::*;
 pub use div::*;
 pub use div_up::*;
+pub use div_with::*;
+pub use exp::*;
 pub use ln::*;
 pub use log_10::*;
 pub use log_2::*;
 pub use mul::*;
 pub use mul_up::*;
+pub use mul_with::*;
 pub use neg::*;
 pub use pow::*;
+pub use saturating_add::*;
+pub use saturating_div::*;
+pub use saturating_mul::*;
+pub use saturating_sub::*;
 pub use sqrt::*;
 pub use sub::*;
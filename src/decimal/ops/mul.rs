@@ -1,65 +1,56 @@
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
 
 pub trait Mul<T>: Sized {
     fn mul(self, rhs: T) -> Self;
 }
 
-/// Multiply another [Decimal] value against itself, including signed multiplication.
-impl Mul<Decimal> for Decimal {
-    fn mul(self, rhs: Decimal) -> Self {
-        Self {
-            value: self
-                .value
-                .checked_mul(rhs.value)
-                .unwrap_or_else(|| panic!("decimal: overflow in method Decimal::mul().checked_mul"))
-                .checked_div(rhs.denominator())
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::mul().checked_div")
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication. The
+/// product is widened to the [DecimalStorage::Wide] integer, so only the result has to fit.
+impl<T: DecimalStorage> Mul<GenericDecimal<T>> for GenericDecimal<T> {
+    fn mul(self, rhs: GenericDecimal<T>) -> Self {
+        let product = T::Wide::from(self.value) * T::Wide::from(rhs.value);
+
+        Self::new(
+            (product / T::Wide::from(rhs.denominator()))
+                .try_into()
+                .unwrap_or_else(|_| {
+                    panic!("decimal: overflow in method {}::mul().try_into", T::NAME)
                 }),
-            scale: self.scale,
-            negative: self.negative != rhs.negative,
-        }
+            self.scale,
+            self.negative != rhs.negative,
+        )
     }
 }
 
-/// Multiply an unsigned integer value against a [Decimal].
-impl Mul<u128> for Decimal {
+/// Multiply an unsigned integer value against a [GenericDecimal].
+impl<T: DecimalStorage> Mul<u128> for GenericDecimal<T> {
     fn mul(self, rhs: u128) -> Self {
-        Self {
-            value: self.value.checked_mul(rhs).unwrap_or_else(|| {
-                panic!("decimal: overflow in method Decimal::mul().checked_mul")
+        Self::new(
+            mul_integer(self.value, rhs).unwrap_or_else(|| {
+                panic!("decimal: overflow in method {}::mul().checked_mul", T::NAME)
             }),
-            scale: self.scale,
-            negative: self.negative,
-        }
+            self.scale,
+            self.negative,
+        )
     }
 }
 
-/// Multiply another [BigDecimal] value against itself, including signed multiplication.
-impl Mul<BigDecimal> for BigDecimal {
-    fn mul(self, rhs: BigDecimal) -> Self {
-        Self {
-            value: self
-                .value
-                .checked_mul(rhs.value)
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method BigDecimal::mul().checked_mul")
-                })
-                .checked_div(self.denominator())
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method BigDecimal::mul().checked_div")
-                }),
-            scale: self.scale,
-            negative: self.negative != rhs.negative,
-        }
-    }
+/// Multiply a value by an unsigned integer in the [DecimalStorage::Wide] integer, which holds
+/// any u128, or `None` if the product does not fit back.
+pub(crate) fn mul_integer<T: DecimalStorage>(value: T, rhs: u128) -> Option<T> {
+    T::Wide::checked_from_u128(rhs)
+        .and_then(|rhs| T::Wide::from(value).checked_mul(rhs))
+        .and_then(|product| product.try_into().ok())
 }
 
 #[cfg(test)]
 mod test {
     use crate::decimal::core::uint::U192;
     use crate::decimal::ops::Mul;
+    use crate::decimal::wide::Decimal256;
     use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
 
     #[test]
     fn test_mul_decimal() {
@@ -111,10 +102,10 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "decimal: overflow in method Decimal::mul().checked_mul")]
+    #[should_panic(expected = "decimal: overflow in method Decimal::mul().try_into")]
     fn test_mul_decimal_panic() {
         let a = Decimal::new(u128::MAX - 1, 3, false);
-        let b = Decimal::new(2, 3, false);
+        let b = Decimal::new(2000, 3, false);
         a.mul(b);
     }
 
@@ -139,6 +130,24 @@ mod test {
         a.mul(b);
     }
 
+    #[test]
+    fn test_mul_u128_big_decimal() {
+        {
+            // test: 98.76 * 555 = 54811.80
+            let a = BigDecimal::new(U192::from(9876), 2, false);
+            let expected = BigDecimal::new(U192::from(5481180), 2, false);
+
+            assert_eq!(a.mul(555u128), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal: overflow in method BigDecimal::mul().checked_mul")]
+    fn test_mul_u128_big_decimal_panic() {
+        let a = BigDecimal::new(U192::MAX, 2, false);
+        a.mul(2u128);
+    }
+
     #[test]
     fn test_mul_u192() {
         {
@@ -157,4 +166,77 @@ mod test {
             assert_eq!(actual, expected);
         }
     }
+
+    #[test]
+    fn test_mul_different_scales() {
+        // the product is divided by the denominator of rhs, so it stays at the scale of self
+        {
+            // test: 1.5 * 2.00 = 3.0
+            let a = BigDecimal::new(U192::from(15), 1, false);
+            let b = BigDecimal::new(U192::from(200), 2, false);
+
+            assert_eq!(a.mul(b), BigDecimal::new(U192::from(30), 1, false));
+        }
+
+        {
+            // test: 2.00 * 1.5 = 3.00
+            let a = BigDecimal::new(U192::from(200), 2, false);
+            let b = BigDecimal::new(U192::from(15), 1, false);
+
+            assert_eq!(a.mul(b), BigDecimal::new(U192::from(300), 2, false));
+        }
+
+        {
+            // test: every width agrees with Decimal
+            let a = Decimal::new(15, 1, false);
+            let b = Decimal::new(200, 2, false);
+
+            assert_eq!(a.mul(b), Decimal::new(30, 1, false));
+            assert_eq!(
+                Decimal256::from(a).mul(Decimal256::from(b)),
+                Decimal256::from(a.mul(b))
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_negative_zero() {
+        {
+            // test: -0.001 * 0.001 = 0, never negative zero
+            let a = Decimal::new(1, 3, true);
+            let b = Decimal::new(1, 3, false);
+            let actual = a.mul(b);
+
+            assert!(actual.is_zero());
+            assert!(!actual.negative);
+        }
+
+        {
+            // test: -0.001 * 0.001 = 0, never negative zero
+            let a = BigDecimal::new(U192::from(1), 3, true);
+            let b = BigDecimal::new(U192::from(1), 3, false);
+            let actual = a.mul(b);
+
+            assert!(actual.value.is_zero());
+            assert!(!actual.negative);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_mul_never_negative_zero(
+            a in 0..1_000_000_000u128, a_negative: bool,
+            b in 0..1_000_000_000u128, b_negative: bool,
+            scale in 0..=9u8,
+        ) {
+            let a = Decimal::new(a, scale, a_negative);
+            let b = Decimal::new(b, scale, b_negative);
+
+            let actual = a.mul(b);
+            assert!(!(actual.negative && actual.is_zero()), "{:?}", actual);
+
+            let actual = BigDecimal::from(a).mul(BigDecimal::from(b));
+            assert!(!(actual.negative && actual.value.is_zero()), "{:?}", actual);
+        }
+    }
 }
@@ -1,71 +1,98 @@
-use crate::decimal::Decimal;
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
 
 pub trait MulUp<T>: Sized {
     fn mul_up(self, rhs: T) -> Self;
 }
 
-/// Multiply another [Decimal] value against itself, including signed multiplication
-/// and round up (ceiling) the value.
-impl MulUp<Decimal> for Decimal {
-    fn mul_up(self, rhs: Decimal) -> Self {
-        let denominator = rhs.denominator();
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication
+/// and round up (ceiling) the value. The product is widened to the [DecimalStorage::Wide]
+/// integer, so only the result has to fit.
+impl<T: DecimalStorage> MulUp<GenericDecimal<T>> for GenericDecimal<T> {
+    fn mul_up(self, rhs: GenericDecimal<T>) -> Self {
+        let denominator = T::Wide::from(rhs.denominator());
+        let product = T::Wide::from(self.value) * T::Wide::from(rhs.value);
 
-        Self {
-            value: self
-                .value
-                .checked_mul(rhs.value)
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::mul_up().checked_mul")
-                })
-                .checked_add(denominator.checked_sub(1).unwrap())
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::mul_up().checked_add")
-                })
-                .checked_div(denominator)
-                .unwrap_or_else(|| {
-                    panic!("decimal: overflow in method Decimal::mul_up().checked_div")
+        Self::new(
+            ((product + denominator - T::Wide::from_small(1)) / denominator)
+                .try_into()
+                .unwrap_or_else(|_| {
+                    panic!("decimal: overflow in method {}::mul_up().try_into", T::NAME)
                 }),
-            scale: self.scale,
-            negative: self.negative != rhs.negative,
-        }
+            self.scale,
+            self.negative != rhs.negative,
+        )
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::decimal::core::uint::U192;
     use crate::decimal::ops::MulUp;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
 
     #[test]
     fn test_mul_up() {
-        // mul of small number
-        {
-            let a = Decimal::new(1, 12, false);
-            let b = Decimal::new(1, 12, false);
-            assert_eq!(a.mul_up(b), Decimal::new(1, 12, false));
-        }
+        // shared (lhs, rhs, expected) table for Decimal and BigDecimal
+        let table = [
+            // mul of small number
+            (
+                Decimal::new(1, 12, false),
+                Decimal::new(1, 12, false),
+                Decimal::new(1, 12, false),
+            ),
+            // mul same precision
+            // 1.000000 * 0.300000 = 0.300000
+            (
+                Decimal::new(1000000, 6, false),
+                Decimal::new(300000, 6, false),
+                Decimal::new(300000, 6, false),
+            ),
+            // mul by zero
+            // 1.00 * 0 = 0.00
+            (
+                Decimal::new(100, 2, false),
+                Decimal::new(0, 0, false),
+                Decimal::new(0, 2, false),
+            ),
+            // mul different decimals increases precision
+            (
+                Decimal::new(1_000_000_000, 9, false),
+                Decimal::new(3, 6, false),
+                Decimal::new(3000, 9, false),
+            ),
+            // mul signed rounds the magnitude up
+            // -0.01 * 0.5 = -0.01
+            (
+                Decimal::new(1, 2, true),
+                Decimal::new(5, 1, false),
+                Decimal::new(1, 2, true),
+            ),
+        ];
 
-        // mul same precision
-        // 1.000000 * 0.300000 = 0.300000
-        {
-            let a = Decimal::new(1000000, 6, false);
-            let b = Decimal::new(300000, 6, false);
-            assert_eq!(a.mul_up(b), Decimal::new(300000, 6, false));
+        for (a, b, expected) in table {
+            assert_eq!(a.mul_up(b), expected);
+            assert_eq!(
+                BigDecimal::from(a).mul_up(BigDecimal::from(b)),
+                BigDecimal::from(expected)
+            );
         }
+    }
 
-        // mul by zero
-        // 1.00 * 0 = 0.00
+    #[test]
+    fn test_mul_up_u192() {
         {
-            let a = Decimal::new(100, 2, false);
-            let b = Decimal::new(0, 0, false);
-            assert_eq!(a.mul_up(b), Decimal::new(0, 2, false));
-        }
+            // test: beyond u128, 340282366920938463463374607431768211455 * 1.5
+            // = 510423550381407695195061911147652317183 rounded up
+            let a = BigDecimal::new(U192::from(u128::MAX), 0, false);
+            let b = BigDecimal::new(U192::from(15), 1, false);
+            let expected = BigDecimal::new(
+                U192::from(u128::MAX) * U192::from(3) / U192::from(2) + U192::from(1),
+                0,
+                false,
+            );
 
-        // mul different decimals increases precision
-        {
-            let a = Decimal::new(1_000_000_000, 9, false);
-            let b = Decimal::new(3, 6, false);
-            assert_eq!(a.mul_up(b), Decimal::new(3000, 9, false));
+            assert_eq!(a.mul_up(b), expected);
         }
     }
 }
@@ -0,0 +1,151 @@
+use crate::decimal::core::{DecimalStorage, RoundingMode};
+use crate::decimal::GenericDecimal;
+
+pub trait MulWith<T>: Sized {
+    fn mul_with(self, rhs: T, mode: RoundingMode) -> Self;
+}
+
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication,
+/// and round the value with the given [RoundingMode]. The product is widened to the
+/// [DecimalStorage::Wide] integer and rounded there, so only the result has to fit.
+impl<T: DecimalStorage> MulWith<GenericDecimal<T>> for GenericDecimal<T> {
+    fn mul_with(self, rhs: GenericDecimal<T>, mode: RoundingMode) -> Self {
+        let negative = self.negative != rhs.negative;
+        let product = T::Wide::from(self.value) * T::Wide::from(rhs.value);
+
+        Self::new(
+            mode.div(product, T::Wide::from(rhs.denominator()), negative)
+                .try_into()
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "decimal: overflow in method {}::mul_with().try_into",
+                        T::NAME
+                    )
+                }),
+            self.scale,
+            negative,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::core::RoundingMode;
+    use crate::decimal::ops::MulWith;
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_mul_with() {
+        {
+            // test: 1.234 * 0.04321 = 0.05332114 = 0.053 rounded half even
+            let a = Decimal::new(1234, 3, false);
+            let b = Decimal::new(4321, 5, false);
+
+            assert_eq!(
+                a.mul_with(b, RoundingMode::HalfEven),
+                Decimal::new(53, 3, false)
+            );
+            assert_eq!(
+                a.mul_with(b, RoundingMode::Ceiling),
+                Decimal::new(54, 3, false)
+            );
+        }
+
+        {
+            // test: 0.5 * 0.5 = 0.25 = 0.2 rounded half even, 0.3 rounded half up
+            let a = Decimal::new(5, 1, false);
+            let b = Decimal::new(5, 1, false);
+
+            assert_eq!(
+                a.mul_with(b, RoundingMode::HalfEven),
+                Decimal::new(2, 1, false)
+            );
+            assert_eq!(
+                a.mul_with(b, RoundingMode::HalfUp),
+                Decimal::new(3, 1, false)
+            );
+        }
+
+        {
+            // test: -0.5 * 0.7 = -0.35 = -0.4 rounded floor, -0.3 rounded ceiling
+            let a = Decimal::new(5, 1, true);
+            let b = Decimal::new(7, 1, false);
+
+            assert_eq!(a.mul_with(b, RoundingMode::Floor), Decimal::new(4, 1, true));
+            assert_eq!(
+                a.mul_with(b, RoundingMode::Ceiling),
+                Decimal::new(3, 1, true)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_with_u128_max() {
+        {
+            // test: u128::MAX * 1.0 at scale 38, the product is widened beyond U192
+            let a = Decimal::new(u128::MAX, 38, false);
+            let one = Decimal::new(10u128.pow(38), 38, false);
+
+            assert_eq!(a.mul_with(one, RoundingMode::HalfEven), a);
+            assert_eq!(a.mul_with(one, RoundingMode::Ceiling), a * one);
+        }
+
+        {
+            // test: u128::MAX * 0.5 at scale 1 = 170141183460469231731687303715884105727.5
+            let a = Decimal::new(u128::MAX, 1, false);
+            let half = Decimal::new(5, 1, false);
+
+            assert_eq!(
+                a.mul_with(half, RoundingMode::HalfEven),
+                Decimal::new(u128::MAX / 2 + 1, 1, false)
+            );
+            assert_eq!(
+                a.mul_with(half, RoundingMode::Floor),
+                Decimal::new(u128::MAX / 2, 1, false)
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_with_big_decimal() {
+        {
+            // test: 1.5 * 0.5 = 0.75 = 0.8 rounded half even
+            let a = BigDecimal::new(U192::from(15), 1, false);
+            let b = BigDecimal::new(U192::from(5), 1, false);
+
+            assert_eq!(
+                a.mul_with(b, RoundingMode::HalfEven),
+                BigDecimal::new(U192::from(8), 1, false)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_mul_with_never_negative_zero(
+            a in 0..1_000_000_000u128, a_negative: bool,
+            b in 0..1_000_000_000u128, b_negative: bool,
+            scale in 0..=9u8,
+            mode in prop::sample::select(vec![
+                RoundingMode::Floor,
+                RoundingMode::Ceiling,
+                RoundingMode::TowardZero,
+                RoundingMode::AwayFromZero,
+                RoundingMode::HalfUp,
+                RoundingMode::HalfDown,
+                RoundingMode::HalfEven,
+            ]),
+        ) {
+            let a = Decimal::new(a, scale, a_negative);
+            let b = Decimal::new(b, scale, b_negative);
+
+            let actual = a.mul_with(b, mode);
+            assert!(!(actual.negative && actual.is_zero()), "{:?}", actual);
+
+            let actual = BigDecimal::from(a).mul_with(BigDecimal::from(b), mode);
+            assert!(!(actual.negative && actual.value.is_zero()), "{:?}", actual);
+        }
+    }
+}
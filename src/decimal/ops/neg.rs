@@ -1,45 +1,17 @@
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::GenericDecimal;
 
 pub trait Neg<T>: Sized {
     fn neg(self) -> Self;
 }
 
-/// An implementation of Neg for [Decimal], which allows the use of - to negate its value.
-impl Neg<Decimal> for Decimal {
+/// An implementation of Neg for [GenericDecimal], which allows the use of - to negate its value.
+impl<T: DecimalStorage> Neg<GenericDecimal<T>> for GenericDecimal<T> {
     fn neg(self) -> Self {
         if self.is_negative() {
-            Self {
-                value: self.value,
-                scale: self.scale,
-                negative: false,
-            }
+            Self::new(self.value, self.scale, false)
         } else if self.is_positive() {
-            Self {
-                value: self.value,
-                scale: self.scale,
-                negative: true,
-            }
-        } else {
-            self
-        }
-    }
-}
-
-/// An implementation of Neg for [BigDecimal], which allows the use of - to negate its value.
-impl Neg<BigDecimal> for BigDecimal {
-    fn neg(self) -> Self {
-        if self.is_negative() {
-            Self {
-                value: self.value,
-                scale: self.scale,
-                negative: false,
-            }
-        } else if self.is_positive() {
-            Self {
-                value: self.value,
-                scale: self.scale,
-                negative: true,
-            }
+            Self::new(self.value, self.scale, true)
         } else {
             self
         }
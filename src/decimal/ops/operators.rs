@@ -0,0 +1,232 @@
+//! Operator overloading with [core::ops] for every [GenericDecimal], including
+//! [Decimal](crate::decimal::Decimal) and [BigDecimal](crate::decimal::BigDecimal), so formulas
+//! like `a * b / c - d` read like math.
+//!
+//! Operators follow the same semantics as the crate traits they are built on, but panic
+//! instead of returning an error, like the primitive integer operators do:
+//! - `+`, `-` and `%` require both operands to have the same scale and panic otherwise.
+//! - `*` and `/` keep the scale of the lhs and truncate toward zero, use
+//!   [MulWith](crate::decimal::ops::MulWith) or [DivWith](crate::decimal::ops::DivWith)
+//!   for other rounding modes. The intermediate product is widened, so only the result has
+//!   to fit.
+//! - `%` is the remainder of truncated division, which takes the sign of the lhs.
+//! - Overflow and division by zero panic, use the checked or saturating traits to avoid this.
+
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::ops::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use crate::decimal::GenericDecimal;
+use core::ops;
+
+impl<T: DecimalStorage> ops::Add for GenericDecimal<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .unwrap_or_else(|e| panic!("decimal: {} in operator {1} + {1}", e, T::NAME))
+    }
+}
+
+impl<T: DecimalStorage> ops::Sub for GenericDecimal<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|e| panic!("decimal: {} in operator {1} - {1}", e, T::NAME))
+    }
+}
+
+impl<T: DecimalStorage> ops::Mul for GenericDecimal<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|e| panic!("decimal: {} in operator {1} * {1}", e, T::NAME))
+    }
+}
+
+impl<T: DecimalStorage> ops::Div for GenericDecimal<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.checked_div(rhs)
+            .unwrap_or_else(|e| panic!("decimal: {} in operator {1} / {1}", e, T::NAME))
+    }
+}
+
+impl<T: DecimalStorage> ops::Rem for GenericDecimal<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        if self.scale != rhs.scale {
+            panic!("decimal: Scale is different in operator {0} % {0}", T::NAME);
+        }
+        if rhs.is_zero() {
+            panic!("decimal: Division by zero in operator {0} % {0}", T::NAME);
+        }
+
+        Self::new(self.value % rhs.value, self.scale, self.negative)
+    }
+}
+
+impl<T: DecimalStorage> ops::Neg for GenericDecimal<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        crate::decimal::ops::Neg::neg(self)
+    }
+}
+
+impl<T: DecimalStorage> ops::AddAssign for GenericDecimal<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: DecimalStorage> ops::SubAssign for GenericDecimal<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: DecimalStorage> ops::MulAssign for GenericDecimal<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: DecimalStorage> ops::DivAssign for GenericDecimal<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: DecimalStorage> ops::RemAssign for GenericDecimal<T> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::{BigDecimal, Decimal};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_operators() {
+        {
+            // test: 1.50 * 2.00 / 0.50 - 4.00 = 2.00
+            let a = Decimal::new(150, 2, false);
+            let b = Decimal::new(200, 2, false);
+            let c = Decimal::new(50, 2, false);
+            let d = Decimal::new(400, 2, false);
+            let expected = Decimal::new(200, 2, false);
+
+            assert_eq!(a * b / c - d, expected);
+        }
+
+        {
+            // test: -(4 + -7) = 3
+            let a = Decimal::new(4, 0, false);
+            let b = Decimal::new(7, 0, true);
+            let expected = Decimal::new(3, 0, false);
+
+            assert_eq!(-(a + b), expected);
+        }
+
+        {
+            // test: 2 / 3 = 0.666 truncated toward zero, -2 / 3 = -0.666
+            let a = Decimal::new(2_000, 3, false);
+            let b = Decimal::new(3, 0, false);
+
+            assert_eq!(a / b, Decimal::new(666, 3, false));
+            assert_eq!(-a / b, Decimal::new(666, 3, true));
+        }
+
+        {
+            // test: 5.5 % 2.0 = 1.5 ; -5.5 % 2.0 = -1.5
+            let a = Decimal::new(55, 1, false);
+            let b = Decimal::new(20, 1, false);
+
+            assert_eq!(a % b, Decimal::new(15, 1, false));
+            assert_eq!(-a % b, Decimal::new(15, 1, true));
+        }
+
+        {
+            // test: u128::MAX at scale 12 * 1 does not overflow the intermediate product
+            let a = Decimal::new(u128::MAX, 12, false);
+            let b = Decimal::new(1_000_000_000_000, 12, false);
+
+            assert_eq!(a * b, a);
+        }
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut a = Decimal::new(100, 2, false);
+        a += Decimal::new(50, 2, false);
+        a -= Decimal::new(25, 2, false);
+        a *= Decimal::new(200, 2, false);
+        a /= Decimal::new(50, 2, false);
+        a %= Decimal::new(300, 2, false);
+
+        // test: ((1.00 + 0.50 - 0.25) * 2.00 / 0.50) % 3.00 = 5.00 % 3.00 = 2.00
+        assert_eq!(a, Decimal::new(200, 2, false));
+
+        let mut b = BigDecimal::new(U192::from(100), 2, false);
+        b += BigDecimal::new(U192::from(50), 2, false);
+        b -= BigDecimal::new(U192::from(25), 2, false);
+        b *= BigDecimal::new(U192::from(200), 2, false);
+        b /= BigDecimal::new(U192::from(50), 2, false);
+        b %= BigDecimal::new(U192::from(300), 2, false);
+
+        assert_eq!(b, BigDecimal::new(U192::from(200), 2, false));
+    }
+
+    #[test]
+    fn test_big_decimal_operators() {
+        // test: (1.5 + 2.5) * 0.5 / 4 - -1 = 1.5
+        let a = BigDecimal::new(U192::from(15), 1, false);
+        let b = BigDecimal::new(U192::from(25), 1, false);
+        let c = BigDecimal::new(U192::from(5), 1, false);
+        let d = BigDecimal::new(U192::from(4), 0, false);
+        let e = BigDecimal::new(U192::from(10), 1, true);
+        let expected = BigDecimal::new(U192::from(15), 1, false);
+
+        assert_eq!((a + b) * c / d - e, expected);
+        assert_eq!(-expected, BigDecimal::new(U192::from(15), 1, true));
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale is different")]
+    fn test_add_different_scale() {
+        let _ = Decimal::new(1, 1, false) + Decimal::new(1, 2, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_rem_by_zero() {
+        let _ = Decimal::new(1, 1, false) % Decimal::new(0, 1, false);
+    }
+
+    proptest! {
+        #[test]
+        fn test_operators_never_negative_zero(
+            a in 0..1_000_000_000u128, a_negative: bool,
+            b in 1..1_000_000_000u128, b_negative: bool,
+            scale in 0..=9u8,
+        ) {
+            let a = Decimal::new(a, scale, a_negative);
+            let b = Decimal::new(b, scale, b_negative);
+
+            for actual in [a + b, a - b, a * b, a / b, a % b, -a, a - a] {
+                assert!(!(actual.negative && actual.is_zero()), "{:?}", actual);
+            }
+
+            let (a, b) = (BigDecimal::from(a), BigDecimal::from(b));
+            for actual in [a + b, a - b, a * b, a / b, a % b, -a, a - a] {
+                assert!(!(actual.negative && actual.value.is_zero()), "{:?}", actual);
+            }
+        }
+    }
+}
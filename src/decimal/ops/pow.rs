@@ -1,15 +1,91 @@
 use crate::decimal::core::Compare;
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::errors::ErrorCode;
 use crate::decimal::ops::sqrt::Sqrt;
-use crate::decimal::ops::{BigMul, Div, Mul, Neg};
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::ops::{CheckedDiv, CheckedMul, Exp, Ln, Mul, Neg, Sub};
+use crate::decimal::{BigDecimal, Decimal, GenericDecimal};
 
 pub trait Pow<T>: Sized {
     fn pow(self, rhs: T) -> Self;
 }
 
+/// Power that returns an error instead of panicking, for a negative base with a non-integer
+/// exponent, a zero base with a negative exponent or a result that does not fit.
+pub trait CheckedPow<T>: Sized {
+    fn checked_pow(self, rhs: T) -> Result<Self, ErrorCode>;
+}
+
+/// Calculate the power of a [GenericDecimal] with an unsigned integer as the exponent, by
+/// repeated squaring with the widened [Mul].
+impl<T: DecimalStorage> Pow<u128> for GenericDecimal<T> {
+    fn pow(self, exp: u128) -> Self {
+        self.checked_pow(exp)
+            .unwrap_or_else(|_| panic!("decimal: overflow in method {}::pow()", T::NAME))
+    }
+}
+
+/// Calculate the power of a [GenericDecimal] with an unsigned integer as the exponent, returning
+/// an error instead of panicking when the result does not fit.
+impl<T: DecimalStorage> CheckedPow<u128> for GenericDecimal<T> {
+    fn checked_pow(self, exp: u128) -> Result<Self, ErrorCode> {
+        let one = Self::new(self.denominator(), self.scale, false);
+
+        if exp == 0 {
+            return Ok(one);
+        }
+
+        let mut current_exp = exp;
+        let mut base = self;
+        let mut result = one;
+
+        while current_exp > 0 {
+            if current_exp % 2 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            current_exp /= 2;
+            if current_exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
 /// Calculate the power of a [Decimal] with another [Decimal] as the exponent.
+///
+/// Exponents of 0, ±0.25, ±0.5, ±1, ±1.25, ±1.5 and other integers are resolved exactly
+/// with square roots and repeated multiplication. Any other real exponent y is split into its
+/// integer part n and fractional part f, so that x^y = x^n · e^(f·ln(x)), which requires a
+/// positive base.
+///
+/// The fractional factor inherits the error of `ln` at [COMPUTE_SCALE], so before truncation
+/// to the scale of the base the relative error stays below 10^-11 for |y| < 6, and grows
+/// slowly with the integer part of y (2·10^-11 at |y| = 20). For results below 100 that is:
+///
+/// | scale  | error in the last place |
+/// |--------|-------------------------|
+/// | 0..=9  | 1 unit (truncation)     |
+/// | 10     | 10 units                |
+/// | 12     | 1,000 units             |
+///
+/// Panics where [CheckedPow] returns an error.
 impl Pow<Decimal> for Decimal {
     fn pow(self, exp: Decimal) -> Self {
+        self.checked_pow(exp).unwrap_or_else(|err| match err {
+            ErrorCode::SignedDecimalsNotSupported => panic!(
+                "decimal: pow not implemented for negative base with exponent: {}",
+                exp
+            ),
+            _ => panic!("decimal: overflow in method Decimal::pow()"),
+        })
+    }
+}
+
+/// Calculate the power of a [Decimal] with another [Decimal] as the exponent like [Pow],
+/// returning [ErrorCode::SignedDecimalsNotSupported] for a negative base with a non-integer
+/// exponent.
+impl CheckedPow<Decimal> for Decimal {
+    fn checked_pow(self, exp: Decimal) -> Result<Self, ErrorCode> {
         let positive = exp.is_positive();
 
         let base = self.to_compute_scale();
@@ -21,110 +97,137 @@ impl Pow<Decimal> for Decimal {
             Some(x) if x.is_zero() => Decimal::one(),
 
             // e.g. x^0.25 = ⁴√x = √(√x) = sqrt(sqrt(x))
-            Some(x) if positive && x.eq(Decimal::zero_point_two_five()).unwrap() => base
-                .sqrt()
-                .expect("sqrt")
-                .to_compute_scale()
-                .sqrt()
-                .expect("sqrt")
-                .to_compute_scale(),
+            Some(x) if positive && x.eq(Decimal::zero_point_two_five()).unwrap() => {
+                root(root(base)?)?
+            }
 
             // e.g. x^0.5 = √x = sqrt(x)
-            Some(x) if positive && x.eq(Decimal::zero_point_five()).unwrap() => {
-                base.sqrt().expect("sqrt").to_compute_scale()
-            }
+            Some(x) if positive && x.eq(Decimal::zero_point_five()).unwrap() => root(base)?,
 
             // e.g. x^1 = x
             Some(x) if positive && x.eq(Decimal::one()).unwrap() => base,
 
             // e.g. x^1.25 = x(√(√x)) = x(sqrt(sqrt(x)))
-            Some(x) if positive && x.eq(Decimal::one_point_two_five()).unwrap() => base.mul(
-                base.sqrt()
-                    .expect("sqrt")
-                    .to_compute_scale()
-                    .sqrt()
-                    .expect("sqrt")
-                    .to_compute_scale(),
-            ),
+            Some(x) if positive && x.eq(Decimal::one_point_two_five()).unwrap() => {
+                base.checked_mul(root(root(base)?)?)?
+            }
 
             // e.g. x^1.50 = x(√x) = x(sqrt(x))
             Some(x) if positive && x.eq(Decimal::one_point_five()).unwrap() => {
-                base.mul(base.sqrt().expect("sqrt").to_compute_scale())
+                base.checked_mul(root(base)?)?
             }
 
             // e.g. x^2
-            Some(x) if positive && x.eq(Decimal::two()).unwrap() => base.mul(self),
+            Some(x) if positive && x.eq(Decimal::two()).unwrap() => base.checked_mul(self)?,
 
             // e.g. x^N
-            Some(x) if positive && x.is_integer() => base.pow(x.abs() as u128),
+            Some(x) if positive && x.is_integer() => base.checked_pow(x.abs_u64() as u128)?,
 
             // e.g. x^-0.25 = 1/x^0.25
             Some(x) if !positive && x.eq(Decimal::zero_point_two_five().neg()).unwrap() => {
-                Decimal::one().div(base.pow(Decimal::zero_point_two_five()).to_compute_scale())
+                inverse(base.checked_pow(Decimal::zero_point_two_five())?)?
             }
 
             // e.g. x^-0.5 = 1/x^0.5
             Some(x) if !positive && x.eq(Decimal::zero_point_five().neg()).unwrap() => {
-                Decimal::one().div(base.sqrt().expect("sqrt").to_compute_scale())
+                inverse(root(base)?)?
             }
 
             // e.g. x^-1 == 1/x
-            Some(x) if !positive && x.eq(Decimal::one().neg()).unwrap() => Decimal::one().div(base),
+            Some(x) if !positive && x.eq(Decimal::one().neg()).unwrap() => {
+                Decimal::one().checked_div(base)?
+            }
 
             // e.g. x^-1.25 == 1/x^1.25
             Some(x) if !positive && x.eq(Decimal::one_point_two_five().neg()).unwrap() => {
-                Decimal::one().div(base.pow(Decimal::one_point_two_five()).to_compute_scale())
+                inverse(base.checked_pow(Decimal::one_point_two_five())?)?
             }
 
             // e.g. x^-1.5 == 1/x^1.5
             Some(x) if !positive && x.eq(Decimal::one_point_five().neg()).unwrap() => {
-                Decimal::one().div(base.pow(Decimal::one_point_five()).to_compute_scale())
+                inverse(base.checked_pow(Decimal::one_point_five())?)?
             }
 
             // e.g. x^-2 == 1/x^2
-            Some(x) if !positive && x.eq(Decimal::two()).unwrap() => {
-                Decimal::one().div(base.pow(Decimal::two()).to_compute_scale())
+            Some(x) if !positive && x.eq(Decimal::two().neg()).unwrap() => {
+                inverse(base.checked_pow(Decimal::two())?)?
             }
 
             // e.g. x^-N == 1/x^N
             Some(x) if !positive && x.is_integer() => {
-                Decimal::one().div(base.pow(x.abs() as u128).to_compute_scale())
+                inverse(base.checked_pow(x.abs_u64() as u128)?)?
             }
 
-            _ => panic!("pow not implemented for exponent: {}", exp.unwrap()),
+            // e.g. 0^y = 0
+            Some(_) if positive && base.is_zero() => Decimal::zero(),
+
+            // e.g. x^y = x^n · e^(f·ln(x))
+            Some(x) if positive => pow_real(base, x)?,
+
+            // e.g. x^-y = (1/x)^y, inverted first while x < 1 to keep the precision of x^y
+            Some(x) if base.lt(Decimal::one()).unwrap() => {
+                pow_real(Decimal::one().checked_div(base)?, x.neg())?
+            }
+
+            // e.g. x^-y = 1/x^y
+            Some(x) => Decimal::one().checked_div(pow_real(base, x.neg())?)?,
+
+            None => unreachable!(),
         };
 
-        result.to_scale(self.scale)
+        Ok(result.to_scale(self.scale))
     }
 }
 
-/// Calculate the power of a [Decimal] with an unsigned integer as the exponent.
-impl Pow<u128> for Decimal {
-    fn pow(self, exp: u128) -> Self {
-        let one = Decimal::one().to_scale(self.scale);
-
-        if exp == 0 {
-            return one;
-        }
+/// √x of a [Decimal] at [COMPUTE_SCALE].
+fn root(x: Decimal) -> Result<Decimal, ErrorCode> {
+    Ok(x.sqrt()?.to_compute_scale())
+}
 
-        let mut current_exp = exp;
-        let mut base = self;
-        let mut result = one;
+/// 1/x of a [Decimal], with x brought to [COMPUTE_SCALE] first.
+fn inverse(x: Decimal) -> Result<Decimal, ErrorCode> {
+    Decimal::one().checked_div(x.to_compute_scale())
+}
 
-        while current_exp > 0 {
-            if current_exp % 2 != 0 {
-                result = result.big_mul(base);
-            }
-            current_exp /= 2;
-            base = base.big_mul(base);
-        }
-        result
+/// Calculate x^y for a positive base and a positive, non-integer exponent, both at
+/// [COMPUTE_SCALE], by splitting y = n + f into x^n · e^(f·ln(x)).
+fn pow_real(base: Decimal, exp: Decimal) -> Result<Decimal, ErrorCode> {
+    if base.is_negative() {
+        return Err(ErrorCode::SignedDecimalsNotSupported);
     }
+
+    let integer = exp.to_scale(0);
+    let fraction = exp.sub(integer.to_compute_scale()).expect("fraction");
+
+    let integer_part = base.checked_pow(integer.value)?;
+    let fraction_part = fraction
+        .mul(base.ln()?)
+        .exp()
+        .map_err(|_| ErrorCode::ExceedsRange)?;
+
+    integer_part.checked_mul(fraction_part)
 }
 
-/// Calculate the power of a [Decimal] with another [Decimal] as the exponent.
+/// Calculate the power of a [BigDecimal] with another [BigDecimal] as the exponent.
+///
+/// Panics where [CheckedPow] returns an error.
 impl Pow<BigDecimal> for BigDecimal {
     fn pow(self, exp: BigDecimal) -> Self {
+        self.checked_pow(exp).unwrap_or_else(|err| match err {
+            ErrorCode::SignedDecimalsNotSupported => panic!(
+                "decimal: pow not implemented for negative base with exponent: {}",
+                exp
+            ),
+            _ => panic!("decimal: overflow in method BigDecimal::pow()"),
+        })
+    }
+}
+
+/// Calculate the power of a [BigDecimal] with another [BigDecimal] as the exponent like [Pow],
+/// returning [ErrorCode::SignedDecimalsNotSupported] for a negative base with a non-integer
+/// exponent.
+impl CheckedPow<BigDecimal> for BigDecimal {
+    fn checked_pow(self, exp: BigDecimal) -> Result<Self, ErrorCode> {
         let positive = !exp.negative;
 
         let base = self.to_compute_scale();
@@ -132,75 +235,115 @@ impl Pow<BigDecimal> for BigDecimal {
 
         let exp = Some(exp);
 
-        match exp {
+        let one = BigDecimal::one();
+
+        Ok(match exp {
             // e.g. x^0 = 1
             Some(x) if x.value.is_zero() => BigDecimal::one(),
 
             // e.g. x^0.25 = ⁴√x = √(√x) = sqrt(sqrt(x))
-            Some(x) if positive && x.eq(&BigDecimal::zero_point_two_five()) => {
-                base.sqrt().expect("sqrt").sqrt().expect("sqrt")
-            }
+            Some(x) if positive && x == BigDecimal::zero_point_two_five() => base.sqrt()?.sqrt()?,
 
             // e.g. x^0.5 = √x = sqrt(x)
-            Some(x) if positive && x.eq(&BigDecimal::zero_point_five()) => {
-                base.sqrt().expect("sqrt")
-            }
+            Some(x) if positive && x == BigDecimal::zero_point_five() => base.sqrt()?,
 
             // e.g. x^1 = x
-            Some(x) if positive && x.eq(&BigDecimal::one()) => base,
+            Some(x) if positive && x == BigDecimal::one() => base,
 
             // e.g. x^1.25 = x(√(√x)) = x(sqrt(sqrt(x)))
-            Some(x) if positive && x.eq(&BigDecimal::one_point_two_five()) => {
-                base.mul(base.sqrt().expect("sqrt").sqrt().expect("sqrt"))
+            Some(x) if positive && x == BigDecimal::one_point_two_five() => {
+                base.checked_mul(base.sqrt()?.sqrt()?)?
             }
 
             // e.g. x^1.50 = x(√x) = x(sqrt(x))
-            Some(x) if positive && x.eq(&BigDecimal::one_point_five()) => {
-                base.mul(base.sqrt().expect("sqrt"))
+            Some(x) if positive && x == BigDecimal::one_point_five() => {
+                base.checked_mul(base.sqrt()?)?
             }
 
             // e.g. x^2
-            Some(x) if positive && x.eq(&BigDecimal::two()) => base.mul(self),
+            Some(x) if positive && x == BigDecimal::two() => base.checked_mul(self)?,
+
+            // e.g. x^N
+            Some(x) if positive && x.is_integer() => base.checked_pow(x.abs_u64() as u128)?,
 
             // e.g. x^-0.25 = 1/x^0.25
-            Some(x) if !positive && x.eq(&BigDecimal::zero_point_two_five().neg()) => {
-                BigDecimal::one().div(base.pow(BigDecimal::zero_point_two_five()))
+            Some(x) if !positive && x == BigDecimal::zero_point_two_five().neg() => {
+                one.checked_div(base.checked_pow(BigDecimal::zero_point_two_five())?)?
             }
 
             // e.g. x^-0.5 = 1/x^0.5
-            Some(x) if !positive && x.eq(&BigDecimal::zero_point_five().neg()) => {
-                BigDecimal::one().div(base.sqrt().expect("sqrt"))
+            Some(x) if !positive && x == BigDecimal::zero_point_five().neg() => {
+                one.checked_div(base.sqrt()?)?
             }
 
             // e.g. x^-1 == 1/x
-            Some(x) if !positive && x.eq(&BigDecimal::one().neg()) => BigDecimal::one().div(base),
+            Some(x) if !positive && x == BigDecimal::one().neg() => one.checked_div(base)?,
 
             // e.g. x^-1.25 == 1/x^1.25
-            Some(x) if !positive && x.eq(&BigDecimal::one_point_two_five().neg()) => {
-                BigDecimal::one().div(base.pow(BigDecimal::one_point_two_five()))
+            Some(x) if !positive && x == BigDecimal::one_point_two_five().neg() => {
+                one.checked_div(base.checked_pow(BigDecimal::one_point_two_five())?)?
             }
 
             // e.g. x^-1.5 == 1/x^1.5
-            Some(x) if !positive && x.eq(&BigDecimal::one_point_five().neg()) => {
-                BigDecimal::one().div(base.pow(BigDecimal::one_point_five()))
+            Some(x) if !positive && x == BigDecimal::one_point_five().neg() => {
+                one.checked_div(base.checked_pow(BigDecimal::one_point_five())?)?
             }
 
             // e.g. x^-2 == 1/x^2
-            Some(x) if !positive && x.eq(&BigDecimal::two()) => {
-                BigDecimal::one().div(base.pow(BigDecimal::two()))
+            Some(x) if !positive && x == BigDecimal::two().neg() => {
+                one.checked_div(base.checked_pow(BigDecimal::two())?)?
             }
 
-            _ => panic!("pow not implemented for exponent: {:?}", exp.unwrap()),
-        }
+            // e.g. x^-N == 1/x^N
+            Some(x) if !positive && x.is_integer() => {
+                one.checked_div(base.checked_pow(x.abs_u64() as u128)?)?
+            }
+
+            // e.g. 0^y = 0
+            Some(_) if positive && base.is_zero() => BigDecimal::zero(),
+
+            // e.g. x^y = x^n · e^(f·ln(x))
+            Some(x) if positive => big_pow_real(base, x)?,
+
+            // e.g. x^-y = (1/x)^y, inverted first while x < 1 to keep the precision of x^y
+            Some(x) if base < BigDecimal::one() => big_pow_real(one.checked_div(base)?, x.neg())?,
+
+            // e.g. x^-y = 1/x^y
+            Some(x) => one.checked_div(big_pow_real(base, x.neg())?)?,
+
+            None => unreachable!(),
+        })
     }
 }
 
+/// Calculate x^y for a positive base and a positive, non-integer exponent, both at
+/// [BIG_COMPUTE_SCALE], by splitting y = n + f into x^n · e^(f·ln(x)).
+fn big_pow_real(base: BigDecimal, exp: BigDecimal) -> Result<BigDecimal, ErrorCode> {
+    if base.is_negative() {
+        return Err(ErrorCode::SignedDecimalsNotSupported);
+    }
+
+    let integer = exp.to_scale(0);
+    let fraction = exp.sub(integer.to_compute_scale()).expect("fraction");
+
+    let integer_part =
+        base.checked_pow(u128::try_from(integer.value).map_err(|_| ErrorCode::ExceedsRange)?)?;
+    let fraction_part = fraction
+        .mul(base.ln()?)
+        .exp()
+        .map_err(|_| ErrorCode::ExceedsRange)?;
+
+    integer_part.checked_mul(fraction_part)
+}
+
 #[cfg(test)]
 #[allow(clippy::inconsistent_digit_grouping)]
 mod test {
     use crate::decimal::core::uint::U192;
-    use crate::decimal::ops::{Div, Pow, Sub};
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::{CheckedPow, Div, Pow, Sub};
     use crate::decimal::{BigDecimal, Decimal, BIG_COMPUTE_SCALE, COMPUTE_SCALE};
+    use proptest::prelude::*;
     use std::str::FromStr;
 
     #[test]
@@ -348,6 +491,160 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_pow_with_real_exp() {
+        // 42^0.3 = 3.068843822095697894...
+        {
+            let base = Decimal::new(42_000000000000, 12, false);
+            let exp = Decimal::new(300000000000, 12, false);
+            let result = base.pow(exp);
+            let expected = Decimal::new(3_068843822092, 12, false);
+            assert_eq!(result, expected);
+        }
+
+        // 42^0.3 = 3.068843
+        {
+            let base = Decimal::new(42_000000, 6, false);
+            let exp = Decimal::new(300000, 6, false);
+            let result = base.pow(exp);
+            let expected = Decimal::new(3_068843, 6, false);
+            assert_eq!(result, expected);
+        }
+
+        // 2^2.718 = 6.579600549699714304...
+        {
+            let base = Decimal::new(2_000000000000, 12, false);
+            let exp = Decimal::new(2_718000000000, 12, false);
+            let result = base.pow(exp);
+            let expected = Decimal::new(6_579600549696, 12, false);
+            assert_eq!(result, expected);
+        }
+
+        // 0.5^-0.3 = 1.231144413344916284...
+        {
+            let base = Decimal::new(500000000000, 12, false);
+            let exp = Decimal::new(300000000000, 12, true);
+            let result = base.pow(exp);
+            let expected = Decimal::new(1_231144413344, 12, false);
+            assert_eq!(result, expected);
+        }
+
+        // 42^-2.718 = 0.000038726516081174...
+        {
+            let base = Decimal::new(42_000000000000, 12, false);
+            let exp = Decimal::new(2_718000000000, 12, true);
+            let result = base.pow(exp);
+            let expected = Decimal::new(38726516, 12, false);
+            assert_eq!(result, expected);
+        }
+
+        // 0.031337^-4.081961 = 1377320.360553034268...
+        {
+            let base = Decimal::new(31337, 6, false);
+            let exp = Decimal::new(4_081961, 6, true);
+            let result = base.pow(exp);
+            let expected = Decimal::new(1377320_360551, 6, false);
+            assert_eq!(result, expected);
+        }
+
+        // 1^0.333333 = 1
+        {
+            let base = Decimal::new(1_000000, 6, false);
+            let exp = Decimal::new(333333, 6, false);
+            let result = base.pow(exp);
+            let expected = Decimal::new(1_000000, 6, false);
+            assert_eq!(result, expected);
+        }
+
+        // 0^0.3 = 0
+        {
+            let base = Decimal::new(0, 6, false);
+            let exp = Decimal::new(300000, 6, false);
+            let result = base.pow(exp);
+            let expected = Decimal::new(0, 6, false);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal: pow not implemented for negative base")]
+    fn test_pow_with_real_exp_negative_base_panic() {
+        let base = Decimal::new(42_000000, 6, true);
+        let exp = Decimal::new(300000, 6, false);
+        base.pow(exp);
+    }
+
+    #[test]
+    #[should_panic(expected = "decimal: pow not implemented for negative base")]
+    fn test_pow_with_big_decimal_real_exp_negative_base_panic() {
+        let base = BigDecimal::from_str("-42").unwrap();
+        let exp = BigDecimal::from_str("0.3").unwrap();
+        base.pow(exp);
+    }
+
+    #[test]
+    fn test_pow_with_negative_two_exp() {
+        // 42^-2 = 1/1764 = 0.000566893424036281...
+        {
+            let base = Decimal::new(42_000000, 6, false);
+            let exp = Decimal::new(2_000000, 6, true);
+            let result = base.pow(exp);
+            let expected = Decimal::new(566, 6, false);
+            assert_eq!(result, expected);
+            assert_eq!(result, Decimal::one().div(base.pow(2u128)).to_scale(6));
+        }
+
+        {
+            let base = BigDecimal::from_str("42").unwrap();
+            let exp = BigDecimal::from_str("-2").unwrap();
+            let result = base.pow(exp);
+            let expected = BigDecimal::from_str("0.000566893424036281").unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_checked_pow() {
+        // error: (-42)^0.3 = ErrorCode::SignedDecimalsNotSupported
+        {
+            let base = Decimal::new(42_000000, 6, true);
+            let exp = Decimal::new(300000, 6, false);
+            assert_eq!(
+                base.checked_pow(exp),
+                Err(ErrorCode::SignedDecimalsNotSupported)
+            );
+        }
+
+        {
+            let base = BigDecimal::from_str("-42").unwrap();
+            let exp = BigDecimal::from_str("-0.3").unwrap();
+            assert_eq!(
+                base.checked_pow(exp),
+                Err(ErrorCode::SignedDecimalsNotSupported)
+            );
+        }
+
+        // error: 0^-1 = ErrorCode::DivisionByZero
+        {
+            let base = Decimal::new(0, 6, false);
+            let exp = Decimal::new(1_000000, 6, true);
+            assert_eq!(base.checked_pow(exp), Err(ErrorCode::DivisionByZero));
+        }
+
+        // error: u128::MAX^2 = ErrorCode::ExceedsRange
+        {
+            let base = Decimal::new(u128::MAX, 0, false);
+            assert_eq!(base.checked_pow(2u128), Err(ErrorCode::ExceedsRange));
+        }
+
+        // (-2)^3 = -8
+        {
+            let base = Decimal::new(2_000000, 6, true);
+            let exp = Decimal::new(3_000000, 6, false);
+            assert_eq!(base.checked_pow(exp), Ok(Decimal::new(8_000000, 6, true)));
+        }
+    }
+
     #[test]
     fn test_pow_with_big_decimal_exp() {
         // 249383740734.349125162518^-1 = 4.009884513943... × 10^-18
@@ -363,4 +660,131 @@ mod test {
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    fn test_pow_with_integer_exp_shared() {
+        // shared (base, exp, expected) table for Decimal and BigDecimal
+        let table = [
+            // 0**100 = 0
+            (
+                Decimal::new(0, 6, false),
+                100u128,
+                Decimal::new(0, 6, false),
+            ),
+            // 10**0 = 1
+            (
+                Decimal::new(10_000000, 6, false),
+                0,
+                Decimal::new(1_000000, 6, false),
+            ),
+            // 2**18 = 262,144
+            (
+                Decimal::new(2_000000, 6, false),
+                18,
+                Decimal::new(262_144_000000, 6, false),
+            ),
+            // (-1.5)**3 = -3.375
+            (
+                Decimal::new(1_500000, 6, true),
+                3,
+                Decimal::new(3_375000, 6, true),
+            ),
+            // 3.41200000**8 = 18368.43602322
+            (
+                Decimal::new(3_41200000, 8, false),
+                8,
+                Decimal::new(18368_43602280, 8, false),
+            ),
+        ];
+
+        for (base, exp, expected) in table {
+            assert_eq!(base.pow(exp), expected);
+            assert_eq!(BigDecimal::from(base).pow(exp), BigDecimal::from(expected));
+        }
+    }
+
+    #[test]
+    fn test_pow_with_big_decimal_integer_exp() {
+        // 42^3 = 74088
+        {
+            let base = BigDecimal::from_str("42").unwrap();
+            let exp = BigDecimal::from_str("3").unwrap();
+            let result = base.pow(exp);
+            let expected = BigDecimal::from_str("74088").unwrap();
+            assert_eq!(result, expected);
+        }
+
+        // 2^-3 = 0.125
+        {
+            let base = BigDecimal::from_str("2").unwrap();
+            let exp = BigDecimal::from_str("-3").unwrap();
+            let result = base.pow(exp);
+            let expected = BigDecimal::from_str("0.125").unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_pow_with_big_decimal_real_exp() {
+        // 42^0.3 = 3.068843822095697894...
+        {
+            let base = BigDecimal::from_str("42").unwrap();
+            let exp = BigDecimal::from_str("0.3").unwrap();
+            let result = base.pow(exp);
+            let expected = BigDecimal::from_str("3.068843822095697891").unwrap();
+            assert_eq!(result, expected);
+        }
+
+        // 0.5^-0.3 = 1.231144413344916284...
+        {
+            let base = BigDecimal::from_str("0.5").unwrap();
+            let exp = BigDecimal::from_str("-0.3").unwrap();
+            let result = base.pow(exp);
+            let expected = BigDecimal::from_str("1.231144413344916283").unwrap();
+            assert_eq!(result, expected);
+        }
+
+        // 42^-2.718 = 0.000038726516081174...
+        {
+            let base = BigDecimal::from_str("42").unwrap();
+            let exp = BigDecimal::from_str("-2.718").unwrap();
+            let result = base.pow(exp);
+            let expected = BigDecimal::from_str("0.000038726516081174").unwrap();
+            assert_eq!(result, expected);
+        }
+
+        // 0^0.3 = 0
+        {
+            let base = BigDecimal::from_str("0").unwrap();
+            let exp = BigDecimal::from_str("0.3").unwrap();
+            let result = base.pow(exp);
+            assert!(result.is_zero());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_full_range_pow_with_real_exp(
+            base in 100000u128..10_000000u128, // 0.100000 .. 10.000000
+            exp in 0u128..2_000000u128, // 0.000000 .. 2.000000
+            negative in proptest::bool::ANY,
+        ) {
+            let scale = 6; // decimal places
+            let precision = 2; // accuracy +/- 0.000001
+            let base_decimal = Decimal::new(base, scale, false);
+            let exp_decimal = Decimal::new(exp, scale, negative);
+            let base_f64: f64 = base_decimal.into();
+            let exp_f64: f64 = exp_decimal.into();
+            let den_f64: f64 = base_decimal.denominator() as f64;
+
+            // f64 powf == Decimal pow
+            {
+                let pow_f64_u128 = (base_f64.powf(exp_f64) * den_f64).floor() as u128;
+                let pow_decimal_u128 = base_decimal.pow(exp_decimal).value;
+                let difference = pow_f64_u128.abs_diff(pow_decimal_u128).lt(&precision);
+
+                assert!(difference, "pow compare\n{}\n{}\n{}^{}", pow_f64_u128, pow_decimal_u128, base_decimal, exp_decimal);
+            }
+        }
+    }
 }
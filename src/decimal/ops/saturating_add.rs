@@ -0,0 +1,127 @@
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
+use core::cmp::Ordering;
+
+/// Saturating addition, which never fails. Operands of different scales are aligned to the
+/// larger scale, like [Align](crate::decimal::ops::Align) does, and the sum is computed in the
+/// wide integer, so only the result clamps.
+pub trait SaturatingAdd<T>: Sized {
+    fn saturating_add(self, rhs: T) -> Self;
+}
+
+/// Add another [GenericDecimal] value to itself, including signed addition, clamping to
+/// [GenericDecimal::max_value] or [GenericDecimal::min_value] at the larger of the two scales
+/// instead of overflowing.
+impl<T: DecimalStorage> SaturatingAdd<GenericDecimal<T>> for GenericDecimal<T> {
+    fn saturating_add(self, rhs: GenericDecimal<T>) -> Self {
+        let scale = self.scale.max(rhs.scale);
+        let clamp = |negative: bool| {
+            if negative {
+                Self::min_value(scale)
+            } else {
+                Self::max_value(scale)
+            }
+        };
+
+        // an operand beyond the wide integer dominates the sum
+        let (lhs, other) = match (self.widen(scale), rhs.widen(scale)) {
+            (Some(lhs), Some(other)) => (lhs, other),
+            (None, _) => return clamp(self.negative),
+            (_, None) => return clamp(rhs.negative),
+        };
+
+        let (value, negative) = if self.negative == rhs.negative {
+            (lhs.checked_add(other), self.negative)
+        } else {
+            match lhs.cmp(&other) {
+                Ordering::Less => (Some(other - lhs), rhs.negative),
+                _ => (Some(lhs - other), self.negative),
+            }
+        };
+
+        match value.and_then(|value| value.try_into().ok()) {
+            Some(value) => Self::new(value, scale, negative),
+            None => clamp(negative),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::ops::SaturatingAdd;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_saturating_add() {
+        {
+            // test: 4 + (-3) = 1
+            let a = Decimal::new(4, 0, false);
+            let b = Decimal::new(3, 0, true);
+            let expected = Decimal::new(1, 0, false);
+
+            assert_eq!(a.saturating_add(b), expected);
+        }
+
+        {
+            // test: u128::MAX + 1 = u128::MAX
+            let a = Decimal::new(u128::MAX, 6, false);
+            let b = Decimal::new(1, 6, false);
+
+            assert_eq!(a.saturating_add(b), Decimal::max_value(6));
+        }
+
+        {
+            // test: -u128::MAX + (-1) = -u128::MAX
+            let a = Decimal::new(u128::MAX, 6, true);
+            let b = Decimal::new(1, 6, true);
+
+            assert_eq!(a.saturating_add(b), Decimal::min_value(6));
+        }
+
+        {
+            // test: 1.0 + 1.25 = 2.25, aligned to the larger scale
+            let a = Decimal::new(10, 1, false);
+            let b = Decimal::new(125, 2, false);
+
+            assert_eq!(a.saturating_add(b), Decimal::new(225, 2, false));
+            assert_eq!(b.saturating_add(a).scale, 2);
+        }
+
+        {
+            // test: u128::MAX at scale 0 + 0.5 = u128::MAX at scale 1
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(5, 1, false);
+
+            assert_eq!(a.saturating_add(b), Decimal::max_value(1));
+        }
+
+        {
+            // test: (u128::MAX / 10 + 1) + (-u128::MAX at scale 1) = 0.5, where the lhs only
+            // fits at scale 1 in the wide integer
+            let a = Decimal::new(u128::MAX / 10 + 1, 0, false);
+            let b = Decimal::new(u128::MAX, 1, true);
+
+            assert_eq!(a.saturating_add(b), Decimal::new(5, 1, false));
+        }
+    }
+
+    #[test]
+    fn test_saturating_add_big_decimal() {
+        {
+            // test: U192::MAX + 1 = U192::MAX
+            let a = BigDecimal::new(U192::MAX, 18, false);
+            let b = BigDecimal::new(U192::from(1), 18, false);
+
+            assert_eq!(a.saturating_add(b), BigDecimal::max_value(18));
+        }
+
+        {
+            // test: -U192::MAX + (-1) = -U192::MAX
+            let a = BigDecimal::new(U192::MAX, 18, true);
+            let b = BigDecimal::new(U192::from(1), 18, true);
+
+            assert_eq!(a.saturating_add(b), BigDecimal::min_value(18));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::ops::CheckedDiv;
+use crate::decimal::GenericDecimal;
+
+/// Saturating division, which never fails. Like the `/` operator, operands of different scales
+/// are accepted and the result keeps the scale of self.
+pub trait SaturatingDiv<T>: Sized {
+    fn saturating_div(self, rhs: T) -> Self;
+}
+
+/// Divide a [GenericDecimal] over another [GenericDecimal], including signed division,
+/// clamping to [GenericDecimal::max_value] or [GenericDecimal::min_value] for the scale instead
+/// of overflowing. Division of a non-zero value by zero clamps the same way, and 0 / 0 returns
+/// zero.
+impl<T: DecimalStorage> SaturatingDiv<GenericDecimal<T>> for GenericDecimal<T> {
+    fn saturating_div(self, rhs: GenericDecimal<T>) -> Self {
+        self.checked_div(rhs).unwrap_or_else(|_| {
+            if self.is_zero() {
+                Self::new(self.value, self.scale, false)
+            } else if self.negative != rhs.negative {
+                Self::min_value(self.scale)
+            } else {
+                Self::max_value(self.scale)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::ops::SaturatingDiv;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_saturating_div() {
+        {
+            // test: -12 / 3 = -4
+            let a = Decimal::new(12, 0, true);
+            let b = Decimal::new(3, 0, false);
+            let expected = Decimal::new(4, 0, true);
+
+            assert_eq!(a.saturating_div(b), expected);
+        }
+
+        {
+            // test: u128::MAX / 0.5 = u128::MAX
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(5, 1, false);
+
+            assert_eq!(a.saturating_div(b), Decimal::max_value(0));
+        }
+
+        {
+            // test: -1.00 / 0 = -u128::MAX
+            let a = Decimal::new(100, 2, true);
+            let b = Decimal::new(0, 0, false);
+
+            assert_eq!(a.saturating_div(b), Decimal::min_value(2));
+        }
+
+        {
+            // test: 0.00 / 0 = 0.00
+            let a = Decimal::new(0, 2, false);
+            let b = Decimal::new(0, 0, false);
+            let expected = Decimal::new(0, 2, false);
+
+            assert_eq!(a.saturating_div(b), expected);
+        }
+    }
+
+    #[test]
+    fn test_saturating_div_scales() {
+        {
+            // test: 1.5 / 0.25 = 6.0, at the scale of the lhs
+            let a = Decimal::new(15, 1, false);
+            let b = Decimal::new(25, 2, false);
+
+            assert_eq!(a.saturating_div(b), Decimal::new(60, 1, false));
+            assert_eq!(b.saturating_div(a), Decimal::new(16, 2, false));
+        }
+
+        {
+            // test: -u128::MAX at scale 2 / 0.1 = -u128::MAX at scale 2
+            let a = Decimal::new(u128::MAX, 2, true);
+            let b = Decimal::new(1, 1, false);
+
+            assert_eq!(a.saturating_div(b), Decimal::min_value(2));
+        }
+    }
+
+    #[test]
+    fn test_saturating_div_big_decimal() {
+        {
+            // test: U192::MAX / 0.1 = U192::MAX
+            let a = BigDecimal::new(U192::MAX, 0, false);
+            let b = BigDecimal::new(U192::from(1), 1, false);
+
+            assert_eq!(a.saturating_div(b), BigDecimal::max_value(0));
+        }
+
+        {
+            // test: 1 / -0 = U192::MAX, zero is never negative
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::from(0), 0, true);
+
+            assert_eq!(a.saturating_div(b), BigDecimal::max_value(0));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::ops::CheckedMul;
+use crate::decimal::GenericDecimal;
+
+/// Saturating multiplication, which never fails. Like the `*` operator, operands of different
+/// scales are accepted and the result keeps the scale of self.
+pub trait SaturatingMul<T>: Sized {
+    fn saturating_mul(self, rhs: T) -> Self;
+}
+
+/// Multiply another [GenericDecimal] value against itself, including signed multiplication,
+/// clamping to [GenericDecimal::max_value] or [GenericDecimal::min_value] for the scale instead
+/// of overflowing. Products smaller than the scale can represent truncate to zero.
+impl<T: DecimalStorage> SaturatingMul<GenericDecimal<T>> for GenericDecimal<T> {
+    fn saturating_mul(self, rhs: GenericDecimal<T>) -> Self {
+        self.checked_mul(rhs).unwrap_or_else(|_| {
+            if self.negative != rhs.negative {
+                Self::min_value(self.scale)
+            } else {
+                Self::max_value(self.scale)
+            }
+        })
+    }
+}
+
+/// Multiply an unsigned integer value against a [GenericDecimal], clamping to
+/// [GenericDecimal::max_value] or [GenericDecimal::min_value] for the scale instead of
+/// overflowing.
+impl<T: DecimalStorage> SaturatingMul<u128> for GenericDecimal<T> {
+    fn saturating_mul(self, rhs: u128) -> Self {
+        self.checked_mul(rhs).unwrap_or_else(|_| {
+            if self.negative {
+                Self::min_value(self.scale)
+            } else {
+                Self::max_value(self.scale)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::ops::SaturatingMul;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_saturating_mul() {
+        {
+            // test: -4 * 3 = -12
+            let a = Decimal::new(4, 0, true);
+            let b = Decimal::new(3, 0, false);
+            let expected = Decimal::new(12, 0, true);
+
+            assert_eq!(a.saturating_mul(b), expected);
+        }
+
+        {
+            // test: 0.000001 * 0.000001 = 0.000000 (underflow truncates to zero)
+            let a = Decimal::new(1, 6, false);
+            let b = Decimal::new(1, 6, false);
+            let expected = Decimal::new(0, 6, false);
+
+            assert_eq!(a.saturating_mul(b), expected);
+        }
+
+        {
+            // test: u128::MAX * 2 = u128::MAX
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(2, 0, false);
+
+            assert_eq!(a.saturating_mul(b), Decimal::max_value(0));
+        }
+
+        {
+            // test: u128::MAX * -2 = -u128::MAX
+            let a = Decimal::new(u128::MAX, 0, false);
+            let b = Decimal::new(2, 0, true);
+
+            assert_eq!(a.saturating_mul(b), Decimal::min_value(0));
+        }
+
+        {
+            // test: -u128::MAX * 2u128 = -u128::MAX
+            let a = Decimal::new(u128::MAX, 2, true);
+
+            assert_eq!(a.saturating_mul(2u128), Decimal::min_value(2));
+        }
+    }
+
+    #[test]
+    fn test_saturating_mul_scales() {
+        {
+            // test: 1.5 * 0.25 = 0.3, at the scale of the lhs
+            let a = Decimal::new(15, 1, false);
+            let b = Decimal::new(25, 2, false);
+
+            assert_eq!(a.saturating_mul(b), Decimal::new(3, 1, false));
+            assert_eq!(b.saturating_mul(a), Decimal::new(37, 2, false));
+        }
+
+        {
+            // test: u128::MAX at scale 2 * -10.0 = -u128::MAX at scale 2
+            let a = Decimal::new(u128::MAX, 2, false);
+            let b = Decimal::new(100, 1, true);
+
+            assert_eq!(a.saturating_mul(b), Decimal::min_value(2));
+        }
+    }
+
+    #[test]
+    fn test_saturating_mul_big_decimal() {
+        {
+            // test: -U192::MAX * -2 = U192::MAX
+            let a = BigDecimal::new(U192::MAX, 0, true);
+            let b = BigDecimal::new(U192::from(2), 0, true);
+
+            assert_eq!(a.saturating_mul(b), BigDecimal::max_value(0));
+        }
+
+        {
+            // test: -U192::MAX * 2u128 = -U192::MAX
+            let a = BigDecimal::new(U192::MAX, 2, true);
+
+            assert_eq!(a.saturating_mul(2u128), BigDecimal::min_value(2));
+        }
+    }
+}
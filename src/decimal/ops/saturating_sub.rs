@@ -0,0 +1,151 @@
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::ops::SaturatingAdd;
+use crate::decimal::GenericDecimal;
+
+/// Saturating subtraction, which never fails, aligning the scales like [SaturatingAdd].
+/// [saturating_sub_unsigned](SaturatingSub::saturating_sub_unsigned) also clamps at zero, for
+/// balances and accumulators that must never go negative.
+pub trait SaturatingSub<T>: Sized {
+    fn saturating_sub(self, rhs: T) -> Self;
+    fn saturating_sub_unsigned(self, rhs: T) -> Self;
+}
+
+/// Subtract another [GenericDecimal] value from itself, including signed subtraction, clamping
+/// to [GenericDecimal::max_value] or [GenericDecimal::min_value] at the larger of the two
+/// scales instead of overflowing.
+impl<T: DecimalStorage> SaturatingSub<GenericDecimal<T>> for GenericDecimal<T> {
+    fn saturating_sub(self, rhs: GenericDecimal<T>) -> Self {
+        self.saturating_add(GenericDecimal::new(rhs.value, rhs.scale, !rhs.negative))
+    }
+
+    /// Subtract like [saturating_sub](SaturatingSub::saturating_sub), but clamp a negative
+    /// result to zero at the larger of the two scales.
+    fn saturating_sub_unsigned(self, rhs: GenericDecimal<T>) -> Self {
+        let difference = self.saturating_sub(rhs);
+
+        if difference.negative {
+            Self::new(T::zero(), difference.scale, false)
+        } else {
+            difference
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::U192;
+    use crate::decimal::ops::SaturatingSub;
+    use crate::decimal::{BigDecimal, Decimal};
+
+    #[test]
+    fn test_saturating_sub() {
+        {
+            // test: 2 - 5 = -3
+            let a = Decimal::new(2, 0, false);
+            let b = Decimal::new(5, 0, false);
+            let expected = Decimal::new(3, 0, true);
+
+            assert_eq!(a.saturating_sub(b), expected);
+        }
+
+        {
+            // test: -u128::MAX - 1 = -u128::MAX
+            let a = Decimal::new(u128::MAX, 3, true);
+            let b = Decimal::new(1, 3, false);
+
+            assert_eq!(a.saturating_sub(b), Decimal::min_value(3));
+        }
+
+        {
+            // test: u128::MAX - (-1) = u128::MAX
+            let a = Decimal::new(u128::MAX, 3, false);
+            let b = Decimal::new(1, 3, true);
+
+            assert_eq!(a.saturating_sub(b), Decimal::max_value(3));
+        }
+    }
+
+    #[test]
+    fn test_saturating_sub_scales() {
+        {
+            // test: 1.5 - 0.25 = 1.25, aligned to the larger scale
+            let a = Decimal::new(15, 1, false);
+            let b = Decimal::new(25, 2, false);
+
+            assert_eq!(a.saturating_sub(b), Decimal::new(125, 2, false));
+        }
+
+        {
+            // test: -u128::MAX - 0.1 = -u128::MAX at scale 1
+            let a = Decimal::new(u128::MAX, 0, true);
+            let b = Decimal::new(1, 1, false);
+
+            assert_eq!(a.saturating_sub(b), Decimal::min_value(1));
+        }
+
+        {
+            // test: 0 - 0 is never negative zero
+            let zero = Decimal::new(0, 2, false);
+
+            assert!(!zero.saturating_sub(zero).negative);
+        }
+    }
+
+    #[test]
+    fn test_saturating_sub_unsigned() {
+        {
+            // test: 2 - 5 = 0
+            let a = Decimal::new(2, 0, false);
+            let b = Decimal::new(5, 0, false);
+
+            assert_eq!(a.saturating_sub_unsigned(b), Decimal::new(0, 0, false));
+        }
+
+        {
+            // test: 5 - 2 = 3
+            let a = Decimal::new(5, 0, false);
+            let b = Decimal::new(2, 0, false);
+
+            assert_eq!(a.saturating_sub_unsigned(b), Decimal::new(3, 0, false));
+        }
+
+        {
+            // test: 0.1 - 0.25 = 0.00, at the larger scale
+            let a = Decimal::new(1, 1, false);
+            let b = Decimal::new(25, 2, false);
+            let actual = a.saturating_sub_unsigned(b);
+
+            assert_eq!((actual.value, actual.scale, actual.negative), (0, 2, false));
+        }
+
+        {
+            // test: -u128::MAX - 1 = 0
+            let a = Decimal::new(u128::MAX, 3, true);
+            let b = Decimal::new(1, 3, false);
+
+            assert_eq!(a.saturating_sub_unsigned(b), Decimal::new(0, 3, false));
+        }
+
+        {
+            // test: 1 - U192::MAX = 0
+            let a = BigDecimal::new(U192::from(1), 0, false);
+            let b = BigDecimal::new(U192::MAX, 0, false);
+
+            assert_eq!(
+                a.saturating_sub_unsigned(b),
+                BigDecimal::new(U192::from(0), 0, false)
+            );
+        }
+    }
+
+    #[test]
+    fn test_saturating_sub_big_decimal() {
+        {
+            // test: -U192::MAX - 1 = -U192::MAX
+            let a = BigDecimal::new(U192::MAX, 0, true);
+            let b = BigDecimal::new(U192::from(1), 0, false);
+
+            assert_eq!(a.saturating_sub(b), BigDecimal::min_value(0));
+        }
+    }
+}
@@ -1,83 +1,49 @@
-use crate::decimal::core::uint::U192;
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::core::{DecimalStorage, UnsignedInt};
+use crate::decimal::GenericDecimal;
 use crate::decimal::errors::ErrorCode;
 
 pub trait Sqrt<T>: Sized {
     fn sqrt(self) -> Result<Self, ErrorCode>;
 }
 
-/// Calculate the square root of a [Decimal] value.
-impl Sqrt<Decimal> for Decimal {
+/// Calculate the square root of a [GenericDecimal] value with Newton's method. The value is
+/// scaled out on itself in the [DecimalStorage::Wide] integer, so any value fits at any scale.
+impl<T: DecimalStorage> Sqrt<GenericDecimal<T>> for GenericDecimal<T> {
     fn sqrt(self) -> Result<Self, ErrorCode> {
-        let big_decimal: BigDecimal = self.into();
-
-        // Note: we always use BigDecimal.sqrt() method for Decimal to
-        // avoid arithmetic overflow of u128 internal value
-        let big_sqrt = big_decimal.sqrt().expect("big_sqrt");
-
-        Ok(big_sqrt.into())
-    }
-}
-
-/// Calculate the square root of a [BigDecimal] value.
-impl Sqrt<BigDecimal> for BigDecimal {
-    fn sqrt(self) -> Result<BigDecimal, ErrorCode> {
-        let zero = BigDecimal::new(U192::from(0u64), self.scale, false);
-        let one = BigDecimal::new(
-            U192::from(1u64)
-                .checked_mul(self.denominator())
-                .expect("one scaled"),
-            self.scale,
-            false,
-        );
-
-        if self.eq(&zero) || self.eq(&one) {
+        if self.is_zero() || self.value == self.denominator() {
             return Ok(self);
         }
 
-        let value = self.value;
-        let denominator = self.denominator();
-
         // we double the precision by scaling out on itself
-        let value_scaled = match value.checked_mul(denominator) {
-            Some(x) => x,
-            None => return Err(ErrorCode::ExceedsPrecisionRange),
-        };
-
-        let bit_length = 192u32
-            .checked_sub(value_scaled.leading_zeros())
-            .expect("bit_length");
+        let value_scaled = T::Wide::from(self.value) * T::Wide::from(self.denominator());
 
-        let mid_length = U192::from(bit_length.checked_div(2).expect("mid_length"));
+        let approx = T::Wide::from_small(1).shift_left(value_scaled.bits() / 2);
 
-        let approx = U192::from(2u128).checked_pow(mid_length).expect("approx");
-
-        let mut y = value_scaled.checked_div(approx).expect("y");
-        let mut y_0 = U192::from(0u128);
-        let y_1 = U192::from(1u128);
-        let threshold = U192::from(1u128);
+        let mut y = value_scaled / approx;
+        let mut y_0 = T::Wide::zero();
+        let threshold = T::Wide::from_small(1);
 
         loop {
-            if y.gt(&y_0) && (y.checked_sub(y_0).unwrap()).gt(&threshold)
-                || y.lt(&y_0) && (y_0.checked_sub(y).unwrap()).gt(&threshold)
-            {
-                let tmp_y = value_scaled.checked_div(y).unwrap();
+            if y > y_0 && y - y_0 > threshold || y < y_0 && y_0 - y > threshold {
+                let tmp_y = value_scaled / y;
                 y_0 = y;
-                y = y.checked_add(tmp_y).unwrap();
-                y >>= y_1;
+                y = (y + tmp_y).shift_right(1);
             } else {
                 break;
             }
         }
 
-        Ok(BigDecimal::new(y, self.scale, self.negative))
+        let y = y.try_into().map_err(|_| ErrorCode::ExceedsPrecisionRange)?;
+
+        Ok(Self::new(y, self.scale, self.negative))
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::decimal::core::uint::U192;
     use crate::decimal::ops::Sqrt;
-    use crate::decimal::Decimal;
+    use crate::decimal::{BigDecimal, Decimal};
     use proptest::prelude::*;
 
     #[test]
@@ -137,6 +103,31 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_sqrt_big_decimal() {
+        {
+            // test: 2**0.5 = 1.414213562373095048
+            let n = BigDecimal::from_u64(2).to_scale(18);
+            let expected = BigDecimal::new(U192::from(1_414_213_562_373_095_048u64), 18, false);
+            assert_eq!(n.sqrt().unwrap(), expected);
+        }
+
+        {
+            // test: value * denominator exceeds a U192 but the root fits,
+            // (10^38)**0.5 = 10^19 at 18 decimals
+            let n = BigDecimal::new(U192::from(10).pow(56.into()), 18, false);
+            let expected = BigDecimal::new(U192::from(10).pow(37.into()), 18, false);
+            assert_eq!(n.sqrt().unwrap(), expected);
+        }
+
+        {
+            // test: the largest value at the largest scale
+            let n = BigDecimal::max_value(57);
+            let root = n.sqrt().unwrap();
+            assert!(root.value > U192::zero());
+        }
+    }
+
     proptest! {
         #[test]
         fn test_full_u64_range_sqrt(
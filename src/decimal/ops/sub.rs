@@ -1,28 +1,17 @@
 use crate::decimal::ops::Add;
-use crate::decimal::{BigDecimal, Decimal};
+use crate::decimal::core::DecimalStorage;
+use crate::decimal::GenericDecimal;
 use crate::decimal::errors::ErrorCode;
 
 pub trait Sub<T>: Sized {
     fn sub(self, rhs: T) -> Result<Self, ErrorCode>;
 }
 
-/// Subtract another [Decimal] value from itself, including signed subtraction.
-impl Sub<Decimal> for Decimal {
-    fn sub(self, rhs: Decimal) -> Result<Self, ErrorCode> {
+/// Subtract another [GenericDecimal] value from itself, including signed subtraction.
+impl<T: DecimalStorage> Sub<GenericDecimal<T>> for GenericDecimal<T> {
+    fn sub(self, rhs: GenericDecimal<T>) -> Result<Self, ErrorCode> {
         // as a - b is always a + (-b) so we let add handle it
-        let new_rhs = Decimal {
-            negative: !rhs.negative,
-            ..rhs
-        };
-        self.add(new_rhs)
-    }
-}
-
-/// Subtract another [BigDecimal] value from itself, including signed subtraction.
-impl Sub<BigDecimal> for BigDecimal {
-    fn sub(self, rhs: BigDecimal) -> Result<Self, ErrorCode> {
-        // as a - b is always a + (-b) so we let add handle it
-        let new_rhs = BigDecimal {
+        let new_rhs = GenericDecimal {
             negative: !rhs.negative,
             ..rhs
         };
@@ -0,0 +1,440 @@
+//! [ScaledDecimal], a [Decimal] whose scale is part of its type.
+
+use crate::decimal::core::RoundingMode;
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::ops::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, DivWith, Exp, Ln, Log10, Log2, MulWith, Pow,
+    Sqrt,
+};
+use crate::decimal::{BigDecimal, Decimal, MAX_SCALE};
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
+
+/// A signed [Decimal] with a scale fixed at compile time, so mixing scales is a type error
+/// instead of [ErrorCode::DifferentScale] at runtime. Scales change only through
+/// [ScaledDecimal::rescale] and [ScaledDecimal::rescale_with].
+///
+/// ```
+/// use logarithm::decimal::scaled::ScaledDecimal;
+///
+/// let price = ScaledDecimal::<6>::new(1_500_000, false); // 1.500000
+/// let amount = ScaledDecimal::<6>::new(2_000_000, false); // 2.000000
+/// assert_eq!((price * amount).to_string(), "3.000000");
+///
+/// let rounded = (price * amount).rescale::<2>().unwrap();
+/// assert_eq!(rounded.to_string(), "3.00");
+/// ```
+///
+/// ```compile_fail
+/// use logarithm::decimal::scaled::ScaledDecimal;
+///
+/// let price = ScaledDecimal::<6>::new(1_500_000, false);
+/// let fee = ScaledDecimal::<2>::new(5, false);
+/// let total = price + fee; // mismatched scales do not compile
+/// ```
+///
+/// Arithmetic uses the same algorithms as [Decimal], see [ops](crate::decimal::ops). The
+/// logarithms, exponentials and square root are computed through [BigDecimal] for precision
+/// at any scale up to [MAX_SCALE].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScaledDecimal<const S: u8>(Decimal);
+
+impl<const S: u8> ScaledDecimal<S> {
+    /// The scale of this type, checked against [MAX_SCALE] at compile time.
+    pub const SCALE: u8 = {
+        assert!(S <= MAX_SCALE, "ScaledDecimal scale exceeds MAX_SCALE");
+        S
+    };
+
+    /// Create a value from a raw value at scale `S`, e.g. `new(1_500, false)` is 1.500 for
+    /// `ScaledDecimal<3>`.
+    pub fn new(value: u128, negative: bool) -> Self {
+        Self(Decimal::new(value, Self::SCALE, negative))
+    }
+
+    pub fn zero() -> Self {
+        Self::new(0, false)
+    }
+
+    pub fn one() -> Self {
+        Self::new(10u128.pow(Self::SCALE.into()), false)
+    }
+
+    /// Create a value from an integer, returning an error if it does not fit at scale `S`.
+    pub fn from_integer(integer: u128, negative: bool) -> Result<Self, ErrorCode> {
+        Self::try_from(Decimal::new(integer, 0, negative).checked_to_scale(Self::SCALE)?)
+    }
+
+    /// The raw value at scale `S`.
+    pub fn value(self) -> u128 {
+        self.0.value
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Convert to a runtime scaled [Decimal].
+    pub fn to_decimal(self) -> Decimal {
+        self.0
+    }
+
+    /// Change the scale to `T`, truncating discarded digits toward zero like
+    /// [Decimal::to_scale] does. Returns an error if the value does not fit at scale `T`.
+    pub fn rescale<const T: u8>(self) -> Result<ScaledDecimal<T>, ErrorCode> {
+        ScaledDecimal::try_from(self.0.checked_to_scale(ScaledDecimal::<T>::SCALE)?)
+    }
+
+    /// Change the scale to `T`, rounding discarded digits with the given [RoundingMode].
+    /// Returns an error if the value does not fit at scale `T`.
+    pub fn rescale_with<const T: u8>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<ScaledDecimal<T>, ErrorCode> {
+        let scale = ScaledDecimal::<T>::SCALE;
+        if scale < S {
+            ScaledDecimal::try_from(self.0.to_scale_with(scale, mode))
+        } else {
+            self.rescale()
+        }
+    }
+
+    /// Compute through [BigDecimal] and convert the result back to scale `S`.
+    fn big(
+        self,
+        f: impl FnOnce(BigDecimal) -> Result<BigDecimal, ErrorCode>,
+    ) -> Result<Self, ErrorCode> {
+        let result = f(self.0.into())?.checked_to_scale(S)?;
+        let value = result
+            .value
+            .try_into()
+            .map_err(|_| ErrorCode::ExceedsRange)?;
+
+        Ok(Self(Decimal::new(value, S, result.negative)))
+    }
+}
+
+impl<const S: u8> Default for ScaledDecimal<S> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const S: u8> From<ScaledDecimal<S>> for Decimal {
+    fn from(scaled: ScaledDecimal<S>) -> Decimal {
+        scaled.0
+    }
+}
+
+impl<const S: u8> TryFrom<Decimal> for ScaledDecimal<S> {
+    type Error = ErrorCode;
+
+    /// Requires the [Decimal] to have scale `S`, rescale it first otherwise.
+    fn try_from(decimal: Decimal) -> Result<Self, ErrorCode> {
+        if decimal.scale != Self::SCALE {
+            return Err(ErrorCode::DifferentScale);
+        }
+        Ok(Self(decimal))
+    }
+}
+
+impl<const S: u8> FromStr for ScaledDecimal<S> {
+    type Err = ErrorCode;
+
+    /// Parses the same grammar as [Decimal]. Parsing is lossless, so non-zero digits beyond
+    /// scale `S` return [ErrorCode::ExceedsPrecisionRange].
+    fn from_str(s: &str) -> Result<Self, ErrorCode> {
+        let decimal = Decimal::from_str(s)?;
+        let scaled = decimal.checked_to_scale(Self::SCALE)?;
+        if decimal.scale > Self::SCALE && scaled != decimal {
+            return Err(ErrorCode::ExceedsPrecisionRange);
+        }
+        Self::try_from(scaled)
+    }
+}
+
+impl<const S: u8> fmt::Display for ScaledDecimal<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const S: u8> ops::Add for ScaledDecimal<S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const S: u8> ops::Sub for ScaledDecimal<S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const S: u8> ops::Mul for ScaledDecimal<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl<const S: u8> ops::Div for ScaledDecimal<S> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl<const S: u8> ops::Rem for ScaledDecimal<S> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl<const S: u8> ops::Neg for ScaledDecimal<S> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<const S: u8> ops::AddAssign for ScaledDecimal<S> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const S: u8> ops::SubAssign for ScaledDecimal<S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const S: u8> ops::MulAssign for ScaledDecimal<S> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const S: u8> ops::DivAssign for ScaledDecimal<S> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const S: u8> CheckedAdd<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn checked_add(self, rhs: Self) -> Result<Self, ErrorCode> {
+        Ok(Self(self.0.checked_add(rhs.0)?))
+    }
+}
+
+impl<const S: u8> CheckedSub<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn checked_sub(self, rhs: Self) -> Result<Self, ErrorCode> {
+        Ok(Self(self.0.checked_sub(rhs.0)?))
+    }
+}
+
+impl<const S: u8> CheckedMul<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn checked_mul(self, rhs: Self) -> Result<Self, ErrorCode> {
+        Ok(Self(self.0.checked_mul(rhs.0)?))
+    }
+}
+
+impl<const S: u8> CheckedDiv<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn checked_div(self, rhs: Self) -> Result<Self, ErrorCode> {
+        Ok(Self(self.0.checked_div(rhs.0)?))
+    }
+}
+
+impl<const S: u8> MulWith<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn mul_with(self, rhs: Self, mode: RoundingMode) -> Self {
+        Self(self.0.mul_with(rhs.0, mode))
+    }
+}
+
+impl<const S: u8> DivWith<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn div_with(self, rhs: Self, mode: RoundingMode) -> Self {
+        Self(self.0.div_with(rhs.0, mode))
+    }
+}
+
+impl<const S: u8> Pow<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn pow(self, exp: Self) -> Self {
+        Self(self.0.pow(exp.0))
+    }
+}
+
+impl<const S: u8> Pow<u128> for ScaledDecimal<S> {
+    fn pow(self, exp: u128) -> Self {
+        Self(self.0.pow(exp))
+    }
+}
+
+impl<const S: u8> Sqrt<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn sqrt(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.sqrt())
+    }
+}
+
+impl<const S: u8> Ln<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn ln(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.ln())
+    }
+}
+
+impl<const S: u8> Log2<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn log2(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.log2())
+    }
+}
+
+impl<const S: u8> Log10<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn log10(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.log10())
+    }
+}
+
+impl<const S: u8> Exp<ScaledDecimal<S>> for ScaledDecimal<S> {
+    fn exp(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.exp())
+    }
+
+    fn exp2(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.exp2())
+    }
+
+    fn exp10(self) -> Result<Self, ErrorCode> {
+        self.big(|x| x.exp10())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::{ApproxEq, RoundingMode};
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::{CheckedAdd, CheckedDiv, Exp, Ln, Log10, Log2, Pow, Sqrt};
+    use crate::decimal::scaled::ScaledDecimal;
+    use crate::decimal::Decimal;
+    use std::str::FromStr;
+
+    type Usd = ScaledDecimal<2>;
+    type Precise = ScaledDecimal<30>;
+
+    #[test]
+    fn test_arithmetic() {
+        let price = Usd::new(1_050, false); // 10.50
+        let quantity = Usd::from_integer(3, false).unwrap();
+
+        assert_eq!(price * quantity, Usd::new(3_150, false));
+        assert_eq!(price - quantity * price, Usd::new(2_100, true));
+        assert_eq!(price / quantity, Usd::new(350, false));
+        assert_eq!(price % quantity, Usd::new(150, false));
+        assert_eq!(-price, Usd::new(1_050, true));
+
+        let mut total = Usd::zero();
+        total += price;
+        total *= quantity;
+        assert_eq!(total.value(), 3_150);
+
+        // error: overflow and division by zero
+        assert_eq!(
+            Usd::new(u128::MAX, false).checked_add(Usd::one()),
+            Err(ErrorCode::ExceedsRange)
+        );
+        assert_eq!(
+            price.checked_div(Usd::zero()),
+            Err(ErrorCode::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_rescale() {
+        let value = ScaledDecimal::<4>::new(12_345, true); // -1.2345
+
+        {
+            let rescaled = value.rescale::<2>().unwrap();
+            assert_eq!(rescaled, Usd::new(123, true));
+            assert_eq!(rescaled.to_decimal().scale, 2);
+        }
+
+        {
+            let rescaled = value.rescale_with::<2>(RoundingMode::Floor).unwrap();
+            assert_eq!(rescaled, Usd::new(124, true));
+        }
+
+        {
+            let rescaled = value.rescale::<6>().unwrap();
+            assert_eq!(rescaled.value(), 1_234_500);
+        }
+
+        {
+            // error: does not fit at the larger scale
+            let value = Usd::new(u128::MAX, false);
+            assert_eq!(value.rescale::<3>(), Err(ErrorCode::ExceedsRange));
+        }
+    }
+
+    #[test]
+    fn test_decimal_interop() {
+        let decimal = Decimal::new(1_050, 2, false);
+
+        assert_eq!(Usd::try_from(decimal), Ok(Usd::new(1_050, false)));
+        assert_eq!(Decimal::from(Usd::new(1_050, false)), decimal);
+        assert_eq!(
+            Usd::try_from(decimal.to_scale(3)),
+            Err(ErrorCode::DifferentScale)
+        );
+
+        assert_eq!(Usd::from_str("10.5"), Ok(Usd::new(1_050, false)));
+        assert_eq!(Usd::from_str("10.500"), Ok(Usd::new(1_050, false)));
+        assert_eq!(
+            Usd::from_str("10.505"),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+        assert_eq!(Usd::new(5, true).to_string(), "-0.05");
+    }
+
+    #[test]
+    fn test_math() {
+        // ln(2) = 0.693147180559945309417232121458176568075500134360255254120...
+        {
+            let two = Precise::from_integer(2, false).unwrap();
+            let expected = Decimal::from_str("0.693147180559945309417232121458").unwrap();
+            let tolerance = Decimal::from_str("0.000000000000000001").unwrap();
+            let ln = two.ln().unwrap().to_decimal();
+            assert_eq!(ln.approx_eq_abs(expected, tolerance), Ok(true));
+        }
+
+        {
+            let two = Usd::from_integer(2, false).unwrap();
+            assert_eq!(two.ln(), Ok(Usd::new(69, false)));
+            assert_eq!(two.log2(), Ok(Usd::one()));
+            assert_eq!(two.log10(), Ok(Usd::new(30, false)));
+            assert_eq!(two.sqrt(), Ok(Usd::new(141, false)));
+            assert_eq!(Usd::one().exp(), Ok(Usd::new(271, false)));
+            assert_eq!(two.exp2(), Ok(Usd::new(400, false)));
+            assert_eq!(two.exp10(), Ok(Usd::new(10_000, false)));
+            assert_eq!(two.pow(10u128), Usd::new(102_400, false));
+        }
+
+        {
+            // test: logarithms of values below one are negative
+            let half = Usd::new(50, false);
+            assert_eq!(half.log2(), Ok(Usd::new(100, true)));
+        }
+    }
+}
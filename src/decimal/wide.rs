@@ -0,0 +1,405 @@
+//! [Decimal256] and [Decimal512], decimals backed by a [U256] and a [U512] for balances whose
+//! 18 decimal values and products do not fit in a [BigDecimal]. Arithmetic is shared with
+//! every other [GenericDecimal], and the integer types are re-exported to build values from a
+//! raw amount and to read their `value`.
+
+use crate::decimal::core::uint::U192;
+pub use crate::decimal::core::uint::{U256, U512};
+use crate::decimal::errors::ErrorCode;
+use crate::decimal::{BigDecimal, Decimal, GenericDecimal, BIG_COMPUTE_SCALE};
+use core::str::FromStr;
+
+/// [Decimal256] representation of a number with a U256 value, scale (precision in terms of
+/// number of decimal places) and a negative boolean to handle signed arithmetic.
+///
+/// ```
+/// use logarithm::decimal::ops::Mul;
+/// use logarithm::decimal::wide::{Decimal256, U256};
+///
+/// // 10^25 tokens with 18 decimals, squared, overflows a BigDecimal
+/// let balance: Decimal256 = "10000000000000000000000000.000000000000000000".parse().unwrap();
+/// let squared = balance.mul(balance);
+/// assert_eq!(squared.to_string(), format!("1{}.{}", "0".repeat(50), "0".repeat(18)));
+///
+/// // a raw amount in the smallest unit, 1.5 tokens with 18 decimals
+/// let amount = Decimal256::new(U256::from(1_500_000_000_000_000_000u128), 18, false);
+/// assert_eq!(amount.to_string(), "1.500000000000000000");
+/// assert_eq!(amount.value, U256::exp10(18) * 3 / 2);
+/// ```
+pub type Decimal256 = GenericDecimal<U256>;
+
+/// [Decimal512] representation of a number with a U512 value, scale (precision in terms of
+/// number of decimal places) and a negative boolean to handle signed arithmetic.
+pub type Decimal512 = GenericDecimal<U512>;
+
+/// Implements the constructors, conversions and text formats of a wide decimal `$name` stored
+/// in `$uint`.
+macro_rules! impl_wide_decimal {
+    ($name:ident, $uint:ident) => {
+        impl $name {
+            pub fn zero() -> Self {
+                Self::new($uint::zero(), BIG_COMPUTE_SCALE, false)
+            }
+
+            pub fn one() -> Self {
+                Self::new(
+                    $uint::from(10).pow(BIG_COMPUTE_SCALE.into()),
+                    BIG_COMPUTE_SCALE,
+                    false,
+                )
+            }
+
+            /// Create a value from an unsigned integer, assumed positive by default.
+            pub fn from_u128(integer: u128) -> Self {
+                Self::new($uint::from(integer), 0, false)
+            }
+        }
+
+        impl From<Decimal> for $name {
+            fn from(decimal: Decimal) -> Self {
+                Self::new(decimal.value.into(), decimal.scale, decimal.negative)
+            }
+        }
+
+        impl From<BigDecimal> for $name {
+            fn from(decimal: BigDecimal) -> Self {
+                Self::new(decimal.value.into(), decimal.scale, decimal.negative)
+            }
+        }
+
+        impl TryFrom<$name> for BigDecimal {
+            type Error = ErrorCode;
+
+            fn try_from(decimal: $name) -> Result<BigDecimal, ErrorCode> {
+                let value = U192::try_from(decimal.value).map_err(|_| ErrorCode::ExceedsRange)?;
+                Ok(BigDecimal::new(value, decimal.scale, decimal.negative))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ErrorCode;
+
+            /// Parses the same grammar as [Decimal], for the full range of the backing integer.
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, ErrorCode> {
+                Self::from_ascii(s.as_bytes())
+            }
+        }
+    };
+}
+
+impl_wide_decimal!(Decimal256, U256);
+impl_wide_decimal!(Decimal512, U512);
+
+impl From<Decimal256> for Decimal512 {
+    fn from(decimal: Decimal256) -> Self {
+        Self::new(decimal.value.into(), decimal.scale, decimal.negative)
+    }
+}
+
+impl TryFrom<Decimal512> for Decimal256 {
+    type Error = ErrorCode;
+
+    fn try_from(decimal: Decimal512) -> Result<Decimal256, ErrorCode> {
+        let value = U256::try_from(decimal.value).map_err(|_| ErrorCode::ExceedsRange)?;
+        Ok(Decimal256::new(value, decimal.scale, decimal.negative))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::decimal::core::uint::{U192, U256, U512};
+    use crate::decimal::core::DecimalStorage;
+    use crate::decimal::core::{ApproxEq, RoundingMode};
+    use crate::decimal::errors::ErrorCode;
+    use crate::decimal::ops::{
+        Align, BigDiv, CheckedAdd, CheckedDiv, CheckedDivUp, CheckedMul, CheckedMulUp, DivUp,
+        DivWith, Mul, MulUp, MulWith, Pow, SaturatingMul, Sqrt,
+    };
+    use crate::decimal::wide::{Decimal256, Decimal512};
+    use crate::decimal::{BigDecimal, Decimal, Decimal64};
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    /// An 18 decimal balance of 10^25 whole tokens, whose square a BigDecimal cannot hold.
+    fn large_balance() -> Decimal256 {
+        Decimal256::from_u128(10u128.pow(25)).to_scale(18)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let balance = large_balance();
+
+        {
+            // test: the product overflows a U192 but fits a U256
+            let squared = balance * balance;
+            assert_eq!(
+                squared,
+                Decimal256::new(U256::from(10).pow(68.into()), 18, false)
+            );
+            assert!(BigDecimal::try_from(squared).is_err());
+        }
+
+        {
+            // test: signed add and sub
+            let a = Decimal256::from_str("1.5").unwrap();
+            let b = Decimal256::from_str("-4.0").unwrap().to_scale(1);
+            assert_eq!(a + b, Decimal256::from_str("-2.5").unwrap());
+            assert_eq!(a - b, Decimal256::from_str("5.5").unwrap());
+            assert_eq!(b - b, Decimal256::new(U256::zero(), 1, false));
+        }
+
+        {
+            // test: div keeps the lhs scale
+            let a = Decimal256::from_str("10.000").unwrap();
+            let b = Decimal256::from_str("-4").unwrap();
+            assert_eq!(a / b, Decimal256::from_str("-2.500").unwrap());
+        }
+
+        {
+            // error: overflow, division by zero and different scales
+            let max = Decimal256::max_value(0);
+            assert_eq!(
+                max.checked_add(Decimal256::from_u128(1)),
+                Err(ErrorCode::ExceedsRange)
+            );
+            assert_eq!(max.checked_mul(max), Err(ErrorCode::ExceedsRange));
+            assert_eq!(
+                balance.checked_div(Decimal256::zero().to_scale(0)),
+                Err(ErrorCode::DivisionByZero)
+            );
+            assert_eq!(balance.checked_add(Decimal256::one()).map(|_| ()), Ok(()));
+            assert_eq!(
+                balance.checked_add(Decimal256::from_u128(1)),
+                Err(ErrorCode::DifferentScale)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pow_and_sqrt() {
+        {
+            // test: (10^25)^2 at 18 decimals in a Decimal256, and ^4 in a Decimal512
+            let balance = large_balance();
+            assert_eq!(balance.pow(2), balance * balance);
+
+            let balance = Decimal512::from(balance);
+            assert_eq!(
+                balance.pow(4),
+                Decimal512::new(U512::from(10).pow(118.into()), 18, false)
+            );
+        }
+
+        {
+            // test: sqrt(2) to 18 decimals
+            let two = Decimal256::from_u128(2).to_scale(18);
+            assert_eq!(two.sqrt().unwrap().to_string(), "1.414213562373095048");
+        }
+
+        {
+            // test: sqrt of the largest value at 18 decimals fits
+            let max = Decimal512::max_value(18);
+            let root = max.sqrt().unwrap();
+            assert!(root * root <= max);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let decimal = Decimal::new(12_345, 2, true);
+        let wide = Decimal256::from(decimal);
+        assert_eq!(wide.to_string(), "-123.45");
+        assert_eq!(BigDecimal::try_from(wide), Ok(BigDecimal::from(decimal)));
+
+        let big = BigDecimal::new(U192::MAX, 18, false);
+        assert_eq!(Decimal256::from(big).value, U256::from(U192::MAX));
+        assert_eq!(
+            Decimal512::from(Decimal256::from(big)),
+            Decimal512::from(big)
+        );
+        assert_eq!(
+            Decimal256::try_from(Decimal512::max_value(0)),
+            Err(ErrorCode::ExceedsRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_and_format() {
+        // test: round trip, including leading fractional zeros
+        for s in [
+            "0",
+            "-0.001",
+            "123.450",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        ] {
+            assert_eq!(Decimal256::from_str(s).unwrap().to_string(), s);
+        }
+
+        // test: negative zero parses as zero
+        assert_eq!(Decimal256::from_str("-0.0").unwrap().to_string(), "0.0");
+
+        // error: invalid input
+        assert_eq!(Decimal256::from_str(""), Err(ErrorCode::ParseErrorEmpty));
+        assert_eq!(
+            Decimal256::from_str("1.2.3"),
+            Err(ErrorCode::ParseErrorInvalidCharacter { position: 3 })
+        );
+        assert_eq!(
+            Decimal256::from_str("1e"),
+            Err(ErrorCode::ParseErrorMissingDigits { position: 2 })
+        );
+        assert_eq!(
+            Decimal256::from_str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            ),
+            Err(ErrorCode::ExceedsRange)
+        );
+    }
+
+    #[test]
+    fn test_shared_grammar_and_format() {
+        {
+            // test: exponents and `_` separators, as for every other width
+            assert_eq!(
+                Decimal256::from_str("1_000.5e-3").unwrap(),
+                Decimal256::new(U256::from(10005), 4, false)
+            );
+            assert_eq!(
+                Decimal512::from_str("-1e100").unwrap(),
+                Decimal512::new(U512::from(10).pow(100.into()), 0, true)
+            );
+            assert_eq!(
+                Decimal256::from_ascii(b"1."),
+                Ok(Decimal256::new(U256::from(1), 0, false))
+            );
+        }
+
+        {
+            // test: the scale is limited by DecimalStorage::MAX_SCALE of the backing integer
+            let smallest = format!("1e-{}", U256::MAX_SCALE);
+            assert_eq!(
+                Decimal256::from_str(&smallest),
+                Ok(Decimal256::new(U256::from(1), U256::MAX_SCALE, false))
+            );
+            assert_eq!(
+                Decimal256::from_str(&format!("1e-{}", U256::MAX_SCALE + 1)),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+            assert_eq!(
+                Decimal512::from_str(&format!("1e-{}", U512::MAX_SCALE + 1)),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+        }
+
+        {
+            // test: precision, width, sign and scientific flags
+            let decimal = Decimal256::from_str("-1234.5678").unwrap();
+            assert_eq!(format!("{:.2}", decimal), "-1234.57");
+            assert_eq!(format!("{:>12.1}", decimal), "     -1234.6");
+            assert_eq!(format!("{:+}", -decimal), "+1234.5678");
+            assert_eq!(format!("{:e}", decimal), "-1.2345678e3");
+            assert_eq!(format!("{:.2E}", Decimal512::max_value(0)), "1.34E154");
+        }
+
+        {
+            // test: write_to matches Display, up to the widest value at the largest scale
+            let decimal = Decimal512::new(U512::MAX, U512::MAX_SCALE, true);
+            let mut buf = [0u8; 160];
+            let len = decimal.write_to(&mut buf).unwrap();
+            assert_eq!(&buf[..len], decimal.to_string().as_bytes());
+            assert_eq!(Decimal512::from_ascii(&buf[..len]), Ok(decimal));
+        }
+    }
+
+    #[test]
+    fn test_shared_ops() {
+        let a = Decimal256::from_str("10.000").unwrap();
+        let b = Decimal256::from_str("3.000").unwrap();
+
+        {
+            // test: rounding up and with a rounding mode, 10 / 3 and 10 * 0.333
+            let third = Decimal256::from_str("0.333").unwrap();
+            assert_eq!(a.div_up(b), Decimal256::from_str("3.334").unwrap());
+            assert_eq!(a.big_div(b), a / b);
+            assert_eq!(a.mul_up(third), Decimal256::from_str("3.330").unwrap());
+            assert_eq!(
+                a.div_with(b, RoundingMode::HalfUp),
+                Decimal256::from_str("3.333").unwrap()
+            );
+            assert_eq!(
+                (-a).mul_with(Decimal256::from_str("0.3335").unwrap(), RoundingMode::Floor),
+                Decimal256::from_str("-3.335").unwrap()
+            );
+            assert_eq!(a.checked_div_up(b.to_scale(0)), Ok(a.div_up(b)));
+            assert_eq!(
+                a.checked_div_up(Decimal256::zero()),
+                Err(ErrorCode::DivisionByZero)
+            );
+        }
+
+        {
+            // test: alignment, tolerance comparisons and rounding to a scale
+            let one = Decimal256::from_u128(1);
+            assert_eq!(
+                one.add_aligned(a),
+                Ok(Decimal256::from_str("11.000").unwrap())
+            );
+            assert!(a.approx_eq_abs(a + one.to_scale(3), one).unwrap());
+            assert!(a.approx_eq_ulps(b, 7_000).unwrap());
+            assert_eq!(
+                Decimal256::from_str("2.5")
+                    .unwrap()
+                    .to_scale_with(0, RoundingMode::HalfEven),
+                Decimal256::from_u128(2)
+            );
+        }
+
+        {
+            // test: the same ops on a Decimal64 and a Decimal512
+            let a = Decimal64::new(10_000, 3, false);
+            let b = Decimal64::new(3_000, 3, false);
+            assert_eq!(a.div_up(b), Decimal64::new(3_334, 3, false));
+            assert_eq!(a.checked_mul_up(b), Ok(Decimal64::new(30_000, 3, false)));
+            assert_eq!(a.mul(3u128), Decimal64::new(30_000, 3, false));
+
+            let max = Decimal512::max_value(0);
+            assert_eq!(max.checked_mul(2u128), Err(ErrorCode::ExceedsRange));
+            assert_eq!(max.saturating_mul(2u128), max);
+        }
+    }
+
+    #[test]
+    fn test_ord() {
+        let one = Decimal256::from_u128(1);
+        assert_eq!(one, one.to_scale(18));
+        assert!(-one < Decimal256::zero());
+        assert!(-one.to_scale(2) > -Decimal256::from_u128(2));
+        assert!(Decimal512::max_value(0) > Decimal512::max_value(154));
+    }
+
+    proptest! {
+        #[test]
+        fn test_mul_matches_big_decimal(
+            lhs in 0..u128::MAX,
+            rhs in 0..u128::MAX,
+            scale in 0u8..=18,
+        ) {
+            let lhs = BigDecimal::new(lhs.into(), scale, false);
+            let rhs = BigDecimal::new(rhs.into(), scale, true);
+
+            // mul through U512 intermediates matches the BigDecimal result when it fits
+            if let Ok(expected) = crate::decimal::ops::CheckedMul::checked_mul(lhs, rhs) {
+                let actual = Decimal256::from(lhs).checked_mul(Decimal256::from(rhs)).unwrap();
+                prop_assert_eq!(actual, Decimal256::from(expected));
+            }
+        }
+
+        #[test]
+        fn test_div_inverts_mul(value in 1..u64::MAX as u128, other in 1..u64::MAX as u128) {
+            let lhs = Decimal256::from_u128(value).to_scale(18);
+            let rhs = Decimal256::from_u128(other).to_scale(18);
+
+            prop_assert_eq!((lhs * rhs) / rhs, lhs);
+        }
+    }
+}
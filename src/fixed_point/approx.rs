@@ -0,0 +1,107 @@
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::ApproxEq;
+use crate::decimal::BigDecimal;
+use crate::fixed_point::FixedPoint;
+use checked_decimal_macro::*;
+
+impl FixedPoint {
+    /// Show if |self - other| <= tolerance, where the sign of each value is passed alongside
+    /// it like the (value, negative) pairs returned by ln(), log2() and log10().
+    pub fn approx_eq_abs(
+        self,
+        negative: bool,
+        other: (FixedPoint, bool),
+        tolerance: FixedPoint,
+    ) -> Option<bool> {
+        to_big_decimal(self, negative)
+            .approx_eq_abs(
+                to_big_decimal(other.0, other.1),
+                to_big_decimal(tolerance, false),
+            )
+            .ok()
+    }
+
+    /// Show if |self - other| <= relative * max(|self|, |other|), where the sign of each value
+    /// is passed alongside it.
+    pub fn approx_eq_rel(
+        self,
+        negative: bool,
+        other: (FixedPoint, bool),
+        relative: FixedPoint,
+    ) -> Option<bool> {
+        to_big_decimal(self, negative)
+            .approx_eq_rel(
+                to_big_decimal(other.0, other.1),
+                to_big_decimal(relative, false),
+            )
+            .ok()
+    }
+
+    /// Show if self and other are at most `ulps` units of 10^-12 apart, where the sign of
+    /// each value is passed alongside it.
+    pub fn approx_eq_ulps(
+        self,
+        negative: bool,
+        other: (FixedPoint, bool),
+        ulps: u128,
+    ) -> Option<bool> {
+        to_big_decimal(self, negative)
+            .approx_eq_ulps(to_big_decimal(other.0, other.1), ulps)
+            .ok()
+    }
+}
+
+fn to_big_decimal(x: FixedPoint, negative: bool) -> BigDecimal {
+    BigDecimal::new(U192::from(x.get()), FixedPoint::scale(), negative)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed_point::FixedPoint;
+    use checked_decimal_macro::*;
+
+    #[test]
+    fn test_approx_eq() {
+        // ln(2) = 0.693147180559945309417232121458176568075500134360255254120...
+        {
+            let (ln, negative) = FixedPoint::from_integer(2).ln().unwrap();
+            let expected = (FixedPoint::new(693147180559u128), false);
+
+            assert_eq!(ln.approx_eq_ulps(negative, expected, 1), Some(true));
+            assert_eq!(
+                ln.approx_eq_abs(negative, expected, FixedPoint::new(1u128)),
+                Some(true)
+            );
+            assert_eq!(
+                ln.approx_eq_rel(negative, expected, FixedPoint::new(10u128)),
+                Some(true)
+            );
+        }
+
+        // ln(0.5) = -0.693147180559945309417232121458176568075500134360255254120...
+        {
+            let (ln, negative) = FixedPoint::new(500000000000u128).ln().unwrap();
+            let expected = (FixedPoint::new(693147180559u128), true);
+            let wrong_sign = (FixedPoint::new(693147180559u128), false);
+
+            assert_eq!(ln.approx_eq_ulps(negative, expected, 1), Some(true));
+            assert_eq!(ln.approx_eq_ulps(negative, wrong_sign, 1), Some(false));
+        }
+
+        // a small value is not approximately a big value, in either order
+        {
+            let small = FixedPoint::new(1u128);
+            let big = FixedPoint::from_integer(42);
+            let tolerance = FixedPoint::new(1_000u128);
+
+            assert_eq!(
+                small.approx_eq_abs(false, (big, false), tolerance),
+                Some(false)
+            );
+            assert_eq!(
+                big.approx_eq_abs(false, (small, false), tolerance),
+                Some(false)
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::decimal::core::uint::U192;
+use crate::decimal::ops::Exp;
+use crate::decimal::BigDecimal;
+use crate::fixed_point::FixedPoint;
+use checked_decimal_macro::*;
+
+impl FixedPoint {
+    /// Calculate e^x, where the sign of x is passed alongside it like the (value, negative)
+    /// pairs returned by ln(), log2() and log10(). Returns None when the result does not fit.
+    pub fn exp(self, negative: bool) -> Option<Self> {
+        from_big_decimal(to_big_decimal(self, negative).exp().ok()?)
+    }
+
+    /// Calculate 2^x, where the sign of x is passed alongside it.
+    pub fn exp2(self, negative: bool) -> Option<Self> {
+        from_big_decimal(to_big_decimal(self, negative).exp2().ok()?)
+    }
+
+    /// Calculate 10^x, where the sign of x is passed alongside it.
+    pub fn exp10(self, negative: bool) -> Option<Self> {
+        from_big_decimal(to_big_decimal(self, negative).exp10().ok()?)
+    }
+}
+
+fn to_big_decimal(x: FixedPoint, negative: bool) -> BigDecimal {
+    BigDecimal::new(U192::from(x.get()), FixedPoint::scale(), negative)
+}
+
+fn from_big_decimal(x: BigDecimal) -> Option<FixedPoint> {
+    Some(FixedPoint::new(x.value.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed_point::FixedPoint;
+    use checked_decimal_macro::*;
+
+    #[test]
+    fn test_exp() {
+        // e^1 = 2.718281828459045235360287471352662497757247093699959574966...
+        {
+            let decimal = FixedPoint::from_integer(1);
+            let actual = decimal.exp(false);
+            let expected = Some(FixedPoint::new(2_718281828459u128));
+            assert_eq!(actual, expected);
+        }
+
+        // e^-1 = 0.367879441171442321595523770161460867445811131031767834507...
+        {
+            let decimal = FixedPoint::from_integer(1);
+            let actual = decimal.exp(true);
+            let expected = Some(FixedPoint::new(367879441171u128));
+            assert_eq!(actual, expected);
+        }
+
+        // e^ln(0.810930216211) = e^-0.209573275158 = 0.810930216216...
+        {
+            let (ln, negative) = FixedPoint::new(810930216211u128).ln().unwrap();
+            let actual = ln.exp(negative);
+            let expected = Some(FixedPoint::new(810930216216u128));
+            assert_eq!(actual, expected);
+        }
+
+        // e^100 does not fit
+        {
+            let decimal = FixedPoint::from_integer(100);
+            let actual = decimal.exp(false);
+            assert_eq!(actual, None);
+        }
+    }
+
+    #[test]
+    fn test_exp2() {
+        // 2^10 = 1024
+        {
+            let decimal = FixedPoint::from_integer(10);
+            let actual = decimal.exp2(false);
+            let expected = Some(FixedPoint::from_integer(1024));
+            assert_eq!(actual, expected);
+        }
+
+        // 2^-0.5 = 0.707106781186547524400844362104849039284835937688474036588...
+        {
+            let decimal = FixedPoint::from_scale(5, 1);
+            let actual = decimal.exp2(true);
+            let expected = Some(FixedPoint::new(707106781186u128));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_exp10() {
+        // 10^3 = 1000
+        {
+            let decimal = FixedPoint::from_integer(3);
+            let actual = decimal.exp10(false);
+            let expected = Some(FixedPoint::from_integer(1000));
+            assert_eq!(actual, expected);
+        }
+
+        // 10^-12 = 0.000000000001
+        {
+            let decimal = FixedPoint::from_integer(12);
+            let actual = decimal.exp10(true);
+            let expected = Some(FixedPoint::new(1u128));
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::decimal::core::parse::parse_decimal;
+use crate::decimal::core::uint::U192;
+use crate::decimal::errors::ErrorCode;
+use crate::fixed_point::{FixedPoint, Integer};
+use checked_decimal_macro::*;
+use std::str::FromStr;
+
+impl FromStr for FixedPoint {
+    type Err = ErrorCode;
+
+    /// Parses the same grammar as [Decimal](crate::decimal::Decimal), accepting the output of
+    /// [Display](std::fmt::Display). Parsing is lossless, so non-zero digits beyond 12 decimal
+    /// places or a negative value return an error.
+    fn from_str(s: &str) -> Result<FixedPoint, ErrorCode> {
+        Ok(FixedPoint::new(parse_unsigned(s, FixedPoint::scale())?))
+    }
+}
+
+impl FromStr for Integer {
+    type Err = ErrorCode;
+
+    /// Parses the same grammar as [Decimal](crate::decimal::Decimal), accepting the output of
+    /// [Display](std::fmt::Display). Parsing is lossless, so non-zero decimal places or a
+    /// negative value return an error.
+    fn from_str(s: &str) -> Result<Integer, ErrorCode> {
+        Ok(Integer::new(parse_unsigned(s, Integer::scale())?))
+    }
+}
+
+/// Parse an unsigned value and rescale it to the given scale, as a raw u128. Trailing zeros
+/// beyond the scale are dropped, any other digit would be lost and returns an error.
+fn parse_unsigned(s: &str, scale: u8) -> Result<u128, ErrorCode> {
+    let parsed = parse_decimal(s.as_bytes(), U192::from(u128::MAX), u8::MAX)?;
+
+    if parsed.negative && !parsed.value.is_zero() {
+        return Err(ErrorCode::SignedDecimalsNotSupported);
+    }
+
+    let value = if parsed.scale > scale {
+        let factor = U192::from(10).checked_pow(U192::from(parsed.scale - scale));
+        match factor {
+            Some(factor) if (parsed.value % factor).is_zero() => parsed.value / factor,
+            None if parsed.value.is_zero() => parsed.value,
+            _ => return Err(ErrorCode::ExceedsPrecisionRange),
+        }
+    } else {
+        parsed
+            .value
+            .checked_mul(U192::from(10u128.pow((scale - parsed.scale).into())))
+            .ok_or(ErrorCode::ExceedsRange)?
+    };
+
+    u128::try_from(value).map_err(|_| ErrorCode::ExceedsRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::decimal::errors::ErrorCode;
+    use crate::fixed_point::{FixedPoint, Integer};
+    use checked_decimal_macro::*;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_fixed_point_string() {
+        {
+            let decimal = FixedPoint::from_integer(1);
+            assert_eq!(FixedPoint::from_str(&decimal.to_string()), Ok(decimal));
+            assert_eq!(FixedPoint::from_str("1.000000000000000"), Ok(decimal));
+        }
+
+        {
+            let decimal = FixedPoint::new(2_718281828459u128);
+            assert_eq!(decimal.to_string(), "2.718281828459");
+            assert_eq!(FixedPoint::from_str("2.718281828459"), Ok(decimal));
+        }
+
+        {
+            let decimal = FixedPoint::new(42u128);
+            assert_eq!(decimal.to_string(), "0.000000000042");
+            assert_eq!(FixedPoint::from_str("42e-12"), Ok(decimal));
+            assert_eq!(FixedPoint::from_str("0.0000000000420"), Ok(decimal));
+        }
+
+        {
+            assert_eq!(
+                FixedPoint::from_str("1.5"),
+                Ok(FixedPoint::new(1_500000000000u128))
+            );
+            assert_eq!(
+                FixedPoint::from_str("0.0000000000001"),
+                Err(ErrorCode::ExceedsPrecisionRange)
+            );
+            assert_eq!(
+                FixedPoint::from_str("-1"),
+                Err(ErrorCode::SignedDecimalsNotSupported)
+            );
+            assert_eq!(
+                FixedPoint::from_str("340282366920938463463374607431768211455"),
+                Err(ErrorCode::ExceedsRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_integer_string() {
+        let integer = Integer::new(42u128);
+        assert_eq!(integer.to_string(), "42");
+        assert_eq!(Integer::from_str("42"), Ok(integer));
+        assert_eq!(Integer::from_str("4.2e1"), Ok(integer));
+        assert_eq!(
+            Integer::from_str("4.2"),
+            Err(ErrorCode::ExceedsPrecisionRange)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_fixed_point_string_round_trip(value: u128) {
+            let decimal = FixedPoint::new(value);
+            prop_assert_eq!(FixedPoint::from_str(&decimal.to_string()), Ok(decimal));
+        }
+
+        #[test]
+        fn test_integer_string_round_trip(value: u128) {
+            let integer = Integer::new(value);
+            prop_assert_eq!(Integer::from_str(&integer.to_string()), Ok(integer));
+        }
+    }
+}
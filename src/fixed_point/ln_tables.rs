@@ -2,7 +2,6 @@ use crate::fixed_point::FixedPoint;
 use crate::fixed_point::Integer;
 use checked_decimal_macro::*;
 use std::ops::{Div, Mul, Add, Sub};
-use ndarray::{arr2, Array2};
 
 impl FixedPoint {
     pub fn ln_tables(self) -> Option<(Self, bool)> {
@@ -140,7 +139,7 @@ impl FixedPoint {
     // INT(LN(index)*scale)
     // where scale is a predetermined precision e.g. 10^12
     fn log_table(self, row: usize, col: usize) -> u128 {
-        let table: Array2<u128> = arr2(&[
+        static TABLE: [[u128; 12]; 9] = [
             [
                 95310179804,
                 9950330853,
@@ -267,9 +266,9 @@ impl FixedPoint {
                 90,
                 8,
             ],
-        ]);
+        ];
 
-        table[[row, col]]
+        TABLE[row][col]
     }
 
 }
@@ -286,37 +285,37 @@ mod tests {
         let d = FixedPoint::new(0);
         let (bit_length, negative) = d.bit_length().unwrap();
         assert_eq!(bit_length, Integer::new(0));
-        assert_eq!(negative, false);
+        assert!(!negative);
 
         // 10 bit length == 3
         let d = FixedPoint::from_integer(10);
         let (bit_length, negative) = d.bit_length().unwrap();
         assert_eq!(bit_length, Integer::new(3));
-        assert_eq!(negative, false);
+        assert!(!negative);
 
         // 0.900000000000 bit length == -1
         let d = FixedPoint::new(900000000000);
         let (bit_length, negative) = d.bit_length().unwrap();
         assert_eq!(bit_length, Integer::new(1));
-        assert_eq!(negative, true);
+        assert!(negative);
 
         // 0.01 bit length == -7
         let d = FixedPoint::from_scale(1, 2);
         let (bit_length, negative) = d.bit_length().unwrap();
         assert_eq!(bit_length, Integer::new(7));
-        assert_eq!(negative, true);
+        assert!(negative);
 
         // 0.000001 bit length == -20
         let d = FixedPoint::from_scale(1, 6);
         let (bit_length, negative) = d.bit_length().unwrap();
         assert_eq!(bit_length, Integer::new(20));
-        assert_eq!(negative, true);
+        assert!(negative);
 
         // 18446744073709551615 bit length == 64
         let d = FixedPoint::new(18446744073709551615);
         let (bit_length, negative) = d.bit_length().unwrap();
         assert_eq!(bit_length, Integer::new(24));
-        assert_eq!(negative, false);
+        assert!(!negative);
     }
 
     #[test]
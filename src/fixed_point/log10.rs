@@ -4,7 +4,7 @@ use std::ops::Div;
 
 impl FixedPoint {
     pub fn log10(self) -> Option<(FixedPoint, bool)> {
-        let x: u128 = self.get().into();
+        let x: u128 = self.get();
         let scale: u128 = 10u128.checked_pow(FixedPoint::scale() as u32)?;
 
         assert!(x > 0, "must be greater than zero");
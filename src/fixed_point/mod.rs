@@ -6,6 +6,10 @@ pub mod square;
 pub mod msb;
 pub mod log2;
 pub mod log10;
+pub mod exp;
+pub mod approx;
+pub mod from_str;
+pub mod numeric;
 pub mod ln_tables;
 
 #[decimal(12)]
@@ -0,0 +1,229 @@
+//! [num_traits] implementations for [FixedPoint] and [Integer]. Both types are unsigned, so
+//! [Signed](num_traits::Signed) is not implemented and negative primitives don't convert.
+//! Conversions to primitives truncate toward zero and return None when the value does not fit.
+
+use crate::decimal::convert::to_float;
+use crate::decimal::core::uint::U192;
+use crate::decimal::core::RoundingMode;
+use crate::decimal::Decimal as SignedDecimal;
+use crate::fixed_point::{FixedPoint, Integer};
+use checked_decimal_macro::Decimal;
+use num_traits::{Bounded, FromPrimitive, Num, ToPrimitive};
+use std::ops::Rem;
+
+macro_rules! impl_num_traits {
+    ($t:ident) => {
+        impl num_traits::Zero for $t {
+            fn zero() -> $t {
+                $t::new(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.get() == 0
+            }
+        }
+
+        impl num_traits::One for $t {
+            fn one() -> $t {
+                $t::new(<$t as Decimal>::one())
+            }
+        }
+
+        impl Rem for $t {
+            type Output = $t;
+
+            fn rem(self, rhs: $t) -> $t {
+                $t::new(self.get() % rhs.get())
+            }
+        }
+
+        impl Num for $t {
+            type FromStrRadixErr = crate::decimal::errors::ErrorCode;
+
+            /// Parses base 10 only, see [FromStr](std::str::FromStr).
+            fn from_str_radix(s: &str, radix: u32) -> Result<$t, Self::FromStrRadixErr> {
+                if radix != 10 {
+                    return Err(crate::decimal::errors::ErrorCode::ParseErrorBase10);
+                }
+                s.parse()
+            }
+        }
+
+        impl Bounded for $t {
+            fn min_value() -> $t {
+                $t::new(u128::MIN)
+            }
+
+            fn max_value() -> $t {
+                $t::new(u128::MAX)
+            }
+        }
+
+        impl num_traits::CheckedAdd for $t {
+            fn checked_add(&self, v: &$t) -> Option<$t> {
+                Some($t::new(self.get().checked_add(v.get())?))
+            }
+        }
+
+        impl num_traits::CheckedSub for $t {
+            fn checked_sub(&self, v: &$t) -> Option<$t> {
+                Some($t::new(self.get().checked_sub(v.get())?))
+            }
+        }
+
+        impl num_traits::CheckedMul for $t {
+            /// Multiply and round down like `*` does, the product is widened to U192.
+            fn checked_mul(&self, v: &$t) -> Option<$t> {
+                let product = U192::from(self.get()) * U192::from(v.get());
+                let value = product / U192::from(<$t as Decimal>::one::<u128>());
+                Some($t::new(value.try_into().ok()?))
+            }
+        }
+
+        impl num_traits::CheckedDiv for $t {
+            /// Divide and round down like `/` does, returning None for a zero divisor.
+            fn checked_div(&self, v: &$t) -> Option<$t> {
+                if v.get() == 0 {
+                    return None;
+                }
+                let numerator = U192::from(self.get()) * U192::from(<$t as Decimal>::one::<u128>());
+                Some($t::new((numerator / U192::from(v.get())).try_into().ok()?))
+            }
+        }
+
+        impl num_traits::Pow<u32> for $t {
+            type Output = $t;
+
+            /// Raise to an integer exponent by repeated squaring with `*`.
+            fn pow(self, rhs: u32) -> $t {
+                num_traits::pow(self, rhs as usize)
+            }
+        }
+
+        impl ToPrimitive for $t {
+            fn to_i64(&self) -> Option<i64> {
+                self.to_u128()?.try_into().ok()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                self.to_u128()?.try_into().ok()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.to_u128()?.try_into().ok()
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                Some(self.get() / <$t as Decimal>::one::<u128>())
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                let float: f32 = to_float(self.get(), $t::scale(), false);
+                float.is_finite().then_some(float)
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(to_float(self.get(), $t::scale(), false))
+            }
+        }
+
+        impl FromPrimitive for $t {
+            fn from_i64(n: i64) -> Option<$t> {
+                FromPrimitive::from_i128(n.into())
+            }
+
+            fn from_i128(n: i128) -> Option<$t> {
+                FromPrimitive::from_u128(n.try_into().ok()?)
+            }
+
+            fn from_u64(n: u64) -> Option<$t> {
+                FromPrimitive::from_u128(n.into())
+            }
+
+            fn from_u128(n: u128) -> Option<$t> {
+                Some($t::new(n.checked_mul(<$t as Decimal>::one())?))
+            }
+
+            fn from_f32(n: f32) -> Option<$t> {
+                from_signed(
+                    SignedDecimal::from_f32_with(n, $t::scale(), RoundingMode::HalfEven).ok()?,
+                )
+            }
+
+            fn from_f64(n: f64) -> Option<$t> {
+                from_signed(
+                    SignedDecimal::from_f64_with(n, $t::scale(), RoundingMode::HalfEven).ok()?,
+                )
+            }
+        }
+    };
+}
+
+impl_num_traits!(FixedPoint);
+impl_num_traits!(Integer);
+
+fn from_signed<T: Decimal<U = u128>>(decimal: SignedDecimal) -> Option<T> {
+    (!decimal.negative).then(|| T::new(decimal.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed_point::{FixedPoint, Integer};
+    use checked_decimal_macro::{Decimal, Factories};
+    use num_traits::{Bounded, CheckedDiv, CheckedMul, FromPrimitive, Num, Pow, ToPrimitive};
+
+    /// Generic numeric code, as a caller would write it
+    fn mean<T: Num + Copy + FromPrimitive + CheckedDiv>(values: &[T]) -> Option<T> {
+        let sum = values.iter().fold(T::zero(), |acc, x| acc + *x);
+        sum.checked_div(&T::from_usize(values.len())?)
+    }
+
+    #[test]
+    fn test_generic() {
+        let values = [
+            FixedPoint::from_f64(1.5).unwrap(),
+            FixedPoint::from_u64(3).unwrap(),
+        ];
+        assert_eq!(mean(&values), Some(FixedPoint::new(2_250000000000u128)));
+
+        let values = [Integer::from_u64(3).unwrap(), Integer::from_u64(4).unwrap()];
+        assert_eq!(mean(&values), Some(Integer::new(3)));
+
+        // error: division by zero
+        assert_eq!(mean::<FixedPoint>(&[]), None);
+    }
+
+    #[test]
+    fn test_primitives() {
+        let fixed = FixedPoint::new(2_750000000000u128);
+        assert_eq!(fixed.to_u8(), Some(2));
+        assert_eq!(fixed.to_f64(), Some(2.75));
+        assert_eq!(FixedPoint::max_value().to_u64(), None);
+
+        // error: negative values and overflow
+        assert_eq!(FixedPoint::from_i64(-1), None);
+        assert_eq!(FixedPoint::from_f64(-0.5), None);
+        assert_eq!(FixedPoint::from_u128(u128::MAX), None);
+        assert_eq!(
+            FixedPoint::from_f64(0.1),
+            Some(FixedPoint::new(100000000000u128))
+        );
+    }
+
+    #[test]
+    fn test_ops() {
+        let fixed = FixedPoint::new(1_500000000000u128);
+
+        assert_eq!(Pow::pow(fixed, 2u32), FixedPoint::new(2_250000000000u128));
+        assert_eq!(
+            fixed % FixedPoint::from_integer(1),
+            FixedPoint::new(500000000000u128)
+        );
+        assert_eq!(FixedPoint::from_str_radix("1.5", 10), Ok(fixed));
+        assert_eq!(FixedPoint::max_value().checked_mul(&fixed), None);
+        assert_eq!(
+            fixed.checked_mul(&fixed),
+            Some(FixedPoint::new(2_250000000000u128))
+        );
+    }
+}
@@ -1,2 +1,28 @@
+//! Math algorithms on fixed point decimals, for use on chain.
+//!
+//! # Features
+//!
+//! The `std` feature is enabled by default, so the crate builds like any std library. For
+//! `no_std` targets, opt out with `default-features = false`:
+//!
+//! - `std` (default): implies `alloc`, enables the `fixed_point` module and
+//!   `std::error::Error` for [ErrorCode](decimal::errors::ErrorCode).
+//! - `alloc`: enables `DecimalFormat::format`, which
+//!   returns a `String`.
+//!
+//! Without either feature, the [decimal] module, including parsing, formatting through
+//! [core::fmt] and the binary encodings, is fully available under `no_std`.
+//!
+//! The `fixed_point` module, with `FixedPoint` and `Integer`, is only available with `std`.
+//! Its types are generated by `checked_decimal_macro`, whose expansion refers to `std::`
+//! paths, so it cannot build under `no_std` regardless of how its own tables are stored.
+//! Use [Decimal](decimal::Decimal) in `no_std` code instead.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod fixed_point;
-pub mod decimal;
\ No newline at end of file
+pub mod decimal;